@@ -0,0 +1,36 @@
+use crate::VirtualDevice;
+use dygma_focus::focusd::{default_socket_path, Focusd};
+use dygma_focus::prelude::*;
+use std::error::Error;
+use std::path::PathBuf;
+
+/// Serves the connected keyboards, or the simulated ones when any are given, until the process is stopped.
+pub fn run(
+    socket: Option<PathBuf>,
+    virtual_devices: &[VirtualDevice],
+) -> Result<(), Box<dyn Error>> {
+    let daemon = if virtual_devices.is_empty() {
+        Focusd::discover()?
+    } else {
+        let mut daemon = Focusd::new();
+        for (index, device) in virtual_devices.iter().enumerate() {
+            let hardware = device.hardware();
+            daemon.add(
+                format!("virtual{}", index),
+                hardware.to_string(),
+                Focus::new_virtual(&hardware)?,
+            );
+        }
+        daemon
+    };
+    let socket = match socket {
+        Some(socket) => socket,
+        None => default_socket_path()?,
+    };
+    println!(
+        "Serving [{}] on {}",
+        daemon.ids().collect::<Vec<&str>>().join(", "),
+        socket.display()
+    );
+    Ok(daemon.run(socket)?)
+}
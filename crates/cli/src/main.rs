@@ -0,0 +1,198 @@
+use crate::metrics::DEFAULT_EXPORTER_ADDRESS;
+use clap::{Parser, Subcommand, ValueEnum};
+use dygma_focus::hardware::types::hardware_virtual::*;
+use dygma_focus::prelude::*;
+use dygma_focus::server::{FocusServer, DEFAULT_SERVER_ADDRESS};
+use std::error::Error;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[cfg(unix)]
+mod focusd;
+mod metrics;
+mod mqtt;
+#[cfg(target_os = "linux")]
+mod pty;
+
+/// Tools for working with Dygma keyboards over the Focus protocol.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Serves a simulated keyboard on a pseudo terminal, for Bazecor or other tools to connect to.
+    #[cfg(target_os = "linux")]
+    #[command(name = "virtual")]
+    Virtual {
+        /// The keyboard to simulate.
+        #[arg(value_enum, default_value_t = VirtualDevice::DefyWired)]
+        device: VirtualDevice,
+    },
+    /// Proxies a real keyboard through a pseudo terminal, logging every request and response as JSON lines.
+    #[cfg(target_os = "linux")]
+    Sniff {
+        /// The serial port of the keyboard, the first one found when omitted.
+        #[arg(short, long)]
+        port: Option<String>,
+        /// The file the exchanges are appended to.
+        #[arg(short, long, default_value = "sniff.jsonl")]
+        output: PathBuf,
+    },
+    /// Shows the name and the keymap value of keys, given either way, such as `LCtrl+C` or `262`.
+    Key {
+        /// The keys to show.
+        #[arg(required = true)]
+        keys: Vec<String>,
+    },
+    /// Serves the Focus API as JSON-RPC over HTTP.
+    Serve {
+        /// The address to listen on.
+        #[arg(short, long, default_value = DEFAULT_SERVER_ADDRESS)]
+        address: String,
+        /// The serial port of the keyboard, the first one found when omitted.
+        #[arg(short, long)]
+        port: Option<String>,
+    },
+    /// Serves the health of the keyboards as Prometheus metrics on `/metrics`.
+    Exporter {
+        /// The address to listen on.
+        #[arg(short, long, default_value = DEFAULT_EXPORTER_ADDRESS)]
+        address: String,
+        /// How often the keyboards are read, in seconds.
+        #[arg(long, default_value_t = 15)]
+        interval: u64,
+        /// The serial port of the keyboard, every one found when omitted.
+        #[arg(short, long)]
+        port: Option<String>,
+    },
+    /// Publishes the keyboard state to MQTT, and runs the commands published to it.
+    Mqtt {
+        /// The host of the MQTT broker.
+        #[arg(long, default_value = "localhost")]
+        host: String,
+        /// The port of the MQTT broker.
+        #[arg(long, default_value_t = 1883)]
+        broker_port: u16,
+        /// The topic every state and command topic is under.
+        #[arg(long, default_value = "dygma")]
+        prefix: String,
+        /// How often the state is published, in seconds.
+        #[arg(long, default_value_t = 5)]
+        interval: u64,
+        /// The serial port of the keyboard, the first one found when omitted.
+        #[arg(short, long)]
+        port: Option<String>,
+    },
+    /// Owns the keyboards and shares them with local clients over a Unix domain socket.
+    #[cfg(unix)]
+    Focusd {
+        /// The socket to listen on, in the runtime directory when omitted.
+        #[arg(short, long)]
+        socket: Option<PathBuf>,
+        /// Serves simulated keyboards instead of the connected ones.
+        #[arg(long = "virtual", value_enum)]
+        virtual_devices: Vec<VirtualDevice>,
+    },
+}
+
+/// A keyboard with a virtual definition.
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub enum VirtualDevice {
+    DefyWired,
+    DefyWireless,
+    RaiseAnsi,
+    RaiseIso,
+    Raise2Ansi,
+    Raise2Iso,
+}
+
+impl VirtualDevice {
+    pub fn hardware(self) -> Hardware {
+        match self {
+            VirtualDevice::DefyWired => DEFY_WIRED_VIRTUAL,
+            VirtualDevice::DefyWireless => DEFY_WIRELESS_VIRTUAL,
+            VirtualDevice::RaiseAnsi => RAISE_ANSI_VIRTUAL,
+            VirtualDevice::RaiseIso => RAISE_ISO_VIRTUAL,
+            VirtualDevice::Raise2Ansi => RAISE_2_ANSI_VIRTUAL,
+            VirtualDevice::Raise2Iso => RAISE_2_ISO_VIRTUAL,
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    env_logger::init();
+
+    match Cli::parse().command {
+        #[cfg(target_os = "linux")]
+        Command::Virtual { device } => pty::serve_virtual(&device.hardware()),
+        #[cfg(target_os = "linux")]
+        Command::Sniff { port, output } => pty::sniff(port.as_deref(), &output),
+        Command::Key { keys } => {
+            for key in keys {
+                let key = match key.parse::<u16>() {
+                    Ok(value) => Key::from(value),
+                    Err(_) => key.parse()?,
+                };
+                println!("{}\t{}", key.encode(), key);
+            }
+            Ok(())
+        }
+        Command::Serve { address, port } => {
+            let focus = match port {
+                Some(port) => Focus::new_via_port(&port).ok(),
+                None => Focus::new_first_available().ok(),
+            };
+            let server = FocusServer::bind(&address, focus)?;
+            println!("Serving the Focus API on http://{}", address);
+            Ok(server.run()?)
+        }
+        Command::Exporter {
+            address,
+            interval,
+            port,
+        } => {
+            let devices = match port {
+                Some(port) => vec![find_device(Some(&port))?],
+                None => Focus::find_all_devices()?,
+            };
+            metrics::run(devices, &address, Duration::from_secs(interval))
+        }
+        Command::Mqtt {
+            host,
+            broker_port,
+            prefix,
+            interval,
+            port,
+        } => mqtt::run(
+            ResilientFocus::new(&find_device(port.as_deref())?)?,
+            mqtt::BridgeOptions {
+                host,
+                port: broker_port,
+                prefix,
+                interval: Duration::from_secs(interval),
+            },
+        ),
+        #[cfg(unix)]
+        Command::Focusd {
+            socket,
+            virtual_devices,
+        } => focusd::run(socket, &virtual_devices),
+    }
+}
+
+/// Finds the keyboard on the serial port, or the first one when no port is given.
+fn find_device(port: Option<&str>) -> Result<Device, FocusError> {
+    Focus::find_all_devices()?
+        .into_iter()
+        .find(|device| port.is_none_or(|port| device.serial_port == port))
+        .ok_or_else(|| match port {
+            Some(port) => FocusError::DeviceNotFoundError {
+                selector: format!("port {}", port),
+            },
+            None => FocusError::NoDevicesDetectedError,
+        })
+}
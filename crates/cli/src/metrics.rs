@@ -0,0 +1,260 @@
+use dygma_focus::prelude::*;
+use log::{debug, warn};
+use std::error::Error;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tiny_http::{Header, Response, Server};
+
+/// The address the exporter listens on by default, only reachable from this machine.
+///
+/// Listen on `0.0.0.0:9467` for a Prometheus server elsewhere on the network.
+pub const DEFAULT_EXPORTER_ADDRESS: &str = "127.0.0.1:9467";
+
+/// The readings of a single keyboard, `None` for values it does not report.
+#[derive(Debug, Default)]
+struct Reading {
+    up: bool,
+    battery_level: Option<Sides<u8>>,
+    battery_status: Option<Sides<u8>>,
+    eeprom_free: Option<u32>,
+    macros_memory: Option<u16>,
+    crc_errors: Option<Sides<u32>>,
+    active_layer: Option<u32>,
+}
+
+/// Serves the health of the keyboards on `/metrics`, reading them every interval until the listener fails.
+pub fn run(devices: Vec<Device>, address: &str, interval: Duration) -> Result<(), Box<dyn Error>> {
+    let mut keyboards = Vec::new();
+    for device in devices {
+        match ResilientFocus::new(&device) {
+            Ok(focus) => keyboards.push(focus),
+            Err(e) => warn!("Skipping {} on {}: {}", device, device.serial_port, e),
+        }
+    }
+    if keyboards.is_empty() {
+        return Err(FocusError::NoDevicesDetectedError.into());
+    }
+
+    let metrics = Arc::new(Mutex::new(String::new()));
+    thread::spawn({
+        let metrics = metrics.clone();
+        move || loop {
+            let readings = keyboards
+                .iter_mut()
+                .map(|focus| (focus.device().clone(), read(focus)))
+                .collect::<Vec<(Device, Reading)>>();
+            *metrics
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = render(&readings);
+            thread::sleep(interval);
+        }
+    });
+
+    let server = Server::http(address).map_err(FocusError::ServerError)?;
+    println!("Serving metrics on http://{}/metrics", address);
+    for request in server.incoming_requests() {
+        let response = if request.url() == "/metrics" {
+            let metrics = metrics
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .clone();
+            Response::from_string(metrics).with_header(
+                Header::from_bytes("Content-Type", "text/plain; version=0.0.4")
+                    .expect("valid header"),
+            )
+        } else {
+            Response::from_string("").with_status_code(404)
+        };
+        if let Err(e) = request.respond(response) {
+            debug!("Failed to respond: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Reads every value, a failure only leaves that value out.
+fn read(focus: &mut ResilientFocus) -> Reading {
+    let mut reading = Reading {
+        up: focus.run(|focus| focus.version()).is_ok(),
+        ..Reading::default()
+    };
+    if !reading.up {
+        return reading;
+    }
+
+    let sides = |left: Result<u8, FocusError>, right: Result<u8, FocusError>| {
+        Some(Sides {
+            left: left.ok()?,
+            right: right.ok()?,
+        })
+    };
+    reading.battery_level = sides(
+        focus.run(|focus| focus.wireless_battery_level_left_get()),
+        focus.run(|focus| focus.wireless_battery_level_right_get()),
+    );
+    reading.battery_status = sides(
+        focus.run(|focus| focus.wireless_battery_status_left_get()),
+        focus.run(|focus| focus.wireless_battery_status_right_get()),
+    );
+    reading.eeprom_free = focus
+        .run(|focus| focus.eeprom_free())
+        .ok()
+        .and_then(|free| free.trim().parse().ok());
+    reading.macros_memory = focus.run(|focus| focus.macros_memory()).ok();
+    reading.crc_errors = focus.run(|focus| focus.hardware_crc_errors_get()).ok();
+    reading.active_layer = focus
+        .run(|focus| focus.layer_state())
+        .ok()
+        .and_then(|layers| layers.iter().rposition(|&active| active))
+        .map(|layer| layer as u32);
+    reading
+}
+
+/// Renders the readings in the Prometheus text format, labelled with the identity of each keyboard.
+fn render(readings: &[(Device, Reading)]) -> String {
+    let mut out = String::new();
+    let mut metric = |name: &str, kind: &str, help: &str, samples: &dyn Fn(&Reading) -> Samples| {
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} {}", name, kind);
+        for (device, reading) in readings {
+            for (side, value) in samples(reading) {
+                let side = side
+                    .map(|side| format!(",side=\"{}\"", side))
+                    .unwrap_or_default();
+                let _ = writeln!(
+                    out,
+                    "{}{{device=\"{}\",port=\"{}\",serial=\"{}\"{}}} {}",
+                    name,
+                    escape(&device.to_string()),
+                    escape(&device.serial_port),
+                    escape(device.serial_number.as_deref().unwrap_or_default()),
+                    side,
+                    value
+                );
+            }
+        }
+    };
+    metric(
+        "dygma_up",
+        "gauge",
+        "Whether the keyboard answered the last read.",
+        &|reading| single(Some(reading.up as u8)),
+    );
+    metric(
+        "dygma_battery_level_percent",
+        "gauge",
+        "The battery level of each side.",
+        &|reading| sides(&reading.battery_level),
+    );
+    metric(
+        "dygma_battery_status",
+        "gauge",
+        "The battery status code of each side.",
+        &|reading| sides(&reading.battery_status),
+    );
+    metric(
+        "dygma_eeprom_free_bytes",
+        "gauge",
+        "The free bytes of the EEPROM.",
+        &|reading| single(reading.eeprom_free),
+    );
+    metric(
+        "dygma_macros_memory_bytes",
+        "gauge",
+        "The bytes available for macros.",
+        &|reading| single(reading.macros_memory),
+    );
+    metric(
+        "dygma_link_crc_errors_total",
+        "counter",
+        "The CRC errors on the link to each side since the keyboard started.",
+        &|reading| sides(&reading.crc_errors),
+    );
+    metric(
+        "dygma_active_layer",
+        "gauge",
+        "The highest active layer.",
+        &|reading| single(reading.active_layer),
+    );
+    out
+}
+
+type Samples = Vec<(Option<&'static str>, f64)>;
+
+fn single<T: Into<f64>>(value: Option<T>) -> Samples {
+    value
+        .map(|value| vec![(None, value.into())])
+        .unwrap_or_default()
+}
+
+fn sides<T: Into<f64> + Copy>(sides: &Option<Sides<T>>) -> Samples {
+    match sides {
+        Some(sides) => vec![
+            (Some("left"), sides.left.into()),
+            (Some("right"), sides.right.into()),
+        ],
+        None => Vec::new(),
+    }
+}
+
+/// Escapes a label value.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dygma_focus::hardware::types::hardware_physical::DEFY_WIRELESS;
+
+    #[test]
+    fn test_render() {
+        let device = Device {
+            hardware: DEFY_WIRELESS,
+            serial_port: "/dev/ttyACM0".to_string(),
+            serial_number: Some("ABC\"1".to_string()),
+        };
+        let up = Reading {
+            up: true,
+            battery_level: Some(Sides {
+                left: 80,
+                right: 75,
+            }),
+            eeprom_free: Some(512),
+            crc_errors: Some(Sides { left: 0, right: 3 }),
+            active_layer: Some(2),
+            ..Reading::default()
+        };
+        let down = Reading::default();
+
+        let out = render(&[(device.clone(), up), (device, down)]);
+        let labels = r#"device="Dygma Defy Wireless",port="/dev/ttyACM0",serial="ABC\"1""#;
+
+        assert!(out.contains("# HELP dygma_up Whether the keyboard answered the last read.\n"));
+        assert!(out.contains("# TYPE dygma_up gauge\n"));
+        assert!(out.contains(&format!("dygma_up{{{}}} 1\n", labels)));
+        assert!(out.contains(&format!("dygma_up{{{}}} 0\n", labels)));
+        assert!(out.contains(&format!(
+            "dygma_battery_level_percent{{{},side=\"left\"}} 80\n",
+            labels
+        )));
+        assert!(out.contains(&format!(
+            "dygma_battery_level_percent{{{},side=\"right\"}} 75\n",
+            labels
+        )));
+        assert!(out.contains("# TYPE dygma_link_crc_errors_total counter\n"));
+        assert!(out.contains(&format!(
+            "dygma_link_crc_errors_total{{{},side=\"right\"}} 3\n",
+            labels
+        )));
+        assert!(out.contains(&format!("dygma_eeprom_free_bytes{{{}}} 512\n", labels)));
+        assert!(out.contains(&format!("dygma_active_layer{{{}}} 2\n", labels)));
+        assert!(!out.contains("dygma_battery_status{"));
+        assert!(!out.contains("dygma_macros_memory_bytes{"));
+    }
+}
@@ -0,0 +1,203 @@
+use dygma_focus::prelude::*;
+use log::{debug, warn};
+use rumqttc::{Client, Event, MqttOptions, Packet, QoS};
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Where the bridge connects and how it names its topics.
+#[derive(Debug, Clone)]
+pub struct BridgeOptions {
+    pub host: String,
+    pub port: u16,
+    pub prefix: String,
+    pub interval: Duration,
+}
+
+/// Bridges the keyboard to MQTT until the broker connection fails.
+///
+/// Every interval the state is published, retained, under `<prefix>/`:
+/// `layer_state`, `battery/{left,right}/{level,status}`, `led/mode` and `led/brightness`.
+/// Commands are taken from `<prefix>/set/layer`, `set/led_mode`, `set/led_all` (`r g b`) and `set/macro`.
+pub fn run(focus: ResilientFocus, options: BridgeOptions) -> Result<(), Box<dyn Error>> {
+    let mut mqtt = MqttOptions::new(
+        format!("dygma-focus-{}", std::process::id()),
+        options.host,
+        options.port,
+    );
+    mqtt.set_keep_alive(Duration::from_secs(30));
+    let (client, mut connection) = Client::new(mqtt, 32);
+    client.subscribe(format!("{}/set/#", options.prefix), QoS::AtLeastOnce)?;
+
+    let focus = Arc::new(Mutex::new(focus));
+    thread::spawn({
+        let focus = focus.clone();
+        let client = client.clone();
+        let prefix = options.prefix.clone();
+        move || loop {
+            publish_state(&focus, &client, &prefix);
+            thread::sleep(options.interval);
+        }
+    });
+
+    for event in connection.iter() {
+        if let Event::Incoming(Packet::Publish(publish)) = event? {
+            let payload = String::from_utf8_lossy(&publish.payload);
+            let Some(command) = command_topic(&options.prefix, &publish.topic) else {
+                debug!("Ignoring message on {}", publish.topic);
+                continue;
+            };
+            let mut focus = focus
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            if let Err(e) = focus.run_once(|focus| run_command(focus, command, payload.trim())) {
+                warn!("Command {} {} failed: {}", command, payload, e);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Gets the command a topic under `<prefix>/set/` stands for.
+fn command_topic<'a>(prefix: &str, topic: &'a str) -> Option<&'a str> {
+    topic
+        .strip_prefix(prefix)?
+        .strip_prefix("/set/")
+        .filter(|command| !command.is_empty())
+}
+
+fn run_command(focus: &mut Focus, command: &str, payload: &str) -> Result<(), FocusError> {
+    match command {
+        "layer" => focus.layer_move_to(u8::from_focus_response(payload)?),
+        "led_mode" => focus.led_mode_set(LedMode::from_focus_response(payload)?),
+        "led_all" => focus.led_all(&RGB::from_focus_response(payload)?),
+        "macro" => focus.macros_trigger(u8::from_focus_response(payload)?),
+        _ => {
+            debug!("Ignoring unknown command: {}", command);
+            Ok(())
+        }
+    }
+}
+
+/// Reads and publishes the state, skipping values the keyboard does not have, such as batteries on wired ones.
+fn publish_state(focus: &Mutex<ResilientFocus>, client: &Client, prefix: &str) {
+    let mut focus = focus
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut state = Vec::new();
+    let mut read = |topic: &str, value: Result<String, FocusError>| match value {
+        Ok(value) => state.push((format!("{}/{}", prefix, topic), value)),
+        Err(e) => debug!("Skipping {}: {}", topic, e),
+    };
+
+    read(
+        "layer_state",
+        focus.run(|focus| focus.layer_state()).map(|layers| {
+            layers
+                .iter()
+                .map(|&active| (active as u8).to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        }),
+    );
+    read(
+        "battery/left/level",
+        focus
+            .run(|focus| focus.wireless_battery_level_left_get())
+            .map(|level| level.to_string()),
+    );
+    read(
+        "battery/right/level",
+        focus
+            .run(|focus| focus.wireless_battery_level_right_get())
+            .map(|level| level.to_string()),
+    );
+    read(
+        "battery/left/status",
+        focus
+            .run(|focus| focus.wireless_battery_status_left_get())
+            .map(|status| status.to_string()),
+    );
+    read(
+        "battery/right/status",
+        focus
+            .run(|focus| focus.wireless_battery_status_right_get())
+            .map(|status| status.to_string()),
+    );
+    read(
+        "led/mode",
+        focus
+            .run(|focus| focus.led_mode_get())
+            .map(|mode| (mode as u8).to_string()),
+    );
+    read(
+        "led/brightness",
+        focus
+            .run(|focus| focus.led_brightness_top_get())
+            .map(|brightness| brightness.to_string()),
+    );
+    drop(focus);
+
+    for (topic, value) in state {
+        if let Err(e) = client.publish(topic, QoS::AtLeastOnce, true, value) {
+            warn!("Publishing failed: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dygma_focus::hardware::types::hardware_virtual::DEFY_WIRED_VIRTUAL;
+
+    #[test]
+    fn test_command_topic() {
+        assert_eq!(Some("layer"), command_topic("dygma", "dygma/set/layer"));
+        assert_eq!(
+            Some("led_all"),
+            command_topic("home/dygma", "home/dygma/set/led_all")
+        );
+        assert_eq!(None, command_topic("dygma", "dygma/layer_state"));
+        assert_eq!(None, command_topic("dygma", "dygma/set/"));
+        assert_eq!(None, command_topic("dygma", "dygmax/set/layer"));
+        assert_eq!(None, command_topic("dygma", "other/set/layer"));
+    }
+
+    #[test]
+    fn test_run_command() {
+        let mut focus = Focus::new_virtual(&DEFY_WIRED_VIRTUAL).unwrap();
+
+        run_command(&mut focus, "layer", "2").unwrap();
+        run_command(&mut focus, "led_mode", "1").unwrap();
+        run_command(&mut focus, "led_all", "255 0 16").unwrap();
+        run_command(&mut focus, "macro", "3").unwrap();
+        run_command(&mut focus, "unknown", "1").unwrap();
+
+        assert!(focus.layer_is_active(2).unwrap());
+        assert!(!focus.layer_is_active(0).unwrap());
+        assert_eq!(
+            LedMode::from_focus_response("1").unwrap(),
+            focus.led_mode_get().unwrap()
+        );
+        assert_eq!(
+            RGB {
+                r: 255,
+                g: 0,
+                b: 16
+            },
+            focus.led_at_get(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_run_command_rejects_bad_payloads() {
+        let mut focus = Focus::new_virtual(&DEFY_WIRED_VIRTUAL).unwrap();
+
+        assert!(run_command(&mut focus, "layer", "top").is_err());
+        assert!(run_command(&mut focus, "led_mode", "255").is_err());
+        assert!(run_command(&mut focus, "led_all", "255 0").is_err());
+        assert!(run_command(&mut focus, "macro", "-1").is_err());
+        assert!(focus.layer_is_active(0).unwrap());
+    }
+}
@@ -0,0 +1,31 @@
+use dygma_focus::exchange::FixtureWriter;
+use dygma_focus::prelude::*;
+use dygma_focus::sniffer::Sniffer;
+use dygma_focus::transport::pty::VirtualPty;
+use std::error::Error;
+use std::path::Path;
+
+/// Serves the simulated keyboard until the process is stopped.
+pub fn serve_virtual(hardware: &Hardware) -> Result<(), Box<dyn Error>> {
+    let pty = VirtualPty::spawn(hardware)?;
+    println!("{} listening on {}", hardware, pty.path().display());
+    Ok(pty.wait()?)
+}
+
+/// Proxies the keyboard until either side fails, appending one JSON object per exchange to the output.
+pub fn sniff(port: Option<&str>, output: &Path) -> Result<(), Box<dyn Error>> {
+    let builder = match port {
+        Some(port) => Focus::builder().port(port),
+        None => Focus::builder(),
+    };
+    let mut sniffer = Sniffer::new(builder.build()?.into_transport())?;
+    let mut fixture = FixtureWriter::append(output)?;
+    println!(
+        "Sniffing on {}, logging to {}",
+        sniffer.path().display(),
+        output.display()
+    );
+
+    sniffer.run(|exchange| fixture.write(&exchange))?;
+    Ok(())
+}
@@ -1,11 +1,20 @@
+use crate::commands;
+use crate::diagnostics::reported;
 use crate::errors::*;
+use crate::framer::parse_numerical_vec;
 use crate::helpers::*;
 use crate::prelude::*;
+use crate::settings::SETTINGS_GET_COMMANDS;
 use crate::MAX_LAYERS;
 use log::trace;
-use std::io::{Read, Write};
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::{ErrorKind, Read, Write};
 use std::str::FromStr;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
 
+#[cfg(feature = "async")]
+use crate::asynchronous::AsyncFocus;
 #[cfg(unix)]
 use crate::platform::posix::Focus;
 #[cfg(windows)]
@@ -13,1051 +22,1220 @@ use crate::platform::windows::Focus;
 #[cfg(target_arch = "wasm32")]
 use crate::platform::wasm::Focus;
 
-/// Public methods
+/// Implements the methods for `Focus` and `AsyncFocus` from a single definition, so only the I/O differs between them.
+///
+/// Every call that talks to the device is wrapped in `io!`, which awaits it in the async versions.
+/// On wasm, where the browser does not allow blocking, `Focus` gets the async versions.
+macro_rules! focus_api {
+    (
+        @sync
+        $(#[$impl_meta:meta])*
+        impl $type:ident {
+            $(
+                $(#[$meta:meta])*
+                $vis:vis fn $name:ident $(<$($generic:ident: $bound:path),+>)? (
+                    &mut $self:ident $(, $arg:ident: $arg_type:ty)* $(,)?
+                ) -> $ret:ty $body:block
+            )*
+        }
+    ) => {
+        $(#[$impl_meta])*
+        impl $type {
+            $(
+                $(#[$meta])*
+                $vis fn $name $(<$($generic: $bound),+>)? (&mut $self $(, $arg: $arg_type)*) -> $ret {
+                    macro_rules! io {
+                        ($call:expr) => {
+                            $call
+                        };
+                    }
+                    $body
+                }
+            )*
+        }
+    };
+    (
+        @async
+        $(#[$impl_meta:meta])*
+        impl $type:ident {
+            $(
+                $(#[$meta:meta])*
+                $vis:vis fn $name:ident $(<$($generic:ident: $bound:path),+>)? (
+                    &mut $self:ident $(, $arg:ident: $arg_type:ty)* $(,)?
+                ) -> $ret:ty $body:block
+            )*
+        }
+    ) => {
+        $(#[$impl_meta])*
+        impl $type {
+            $(
+                $(#[$meta])*
+                $vis async fn $name $(<$($generic: $bound),+>)? (&mut $self $(, $arg: $arg_type)*) -> $ret {
+                    macro_rules! io {
+                        ($call:expr) => {
+                            $call.await
+                        };
+                    }
+                    $body
+                }
+            )*
+        }
+    };
+    (
+        $(#[$impl_meta:meta])*
+        impl $sync_type:ident, $async_type:ident { $($methods:tt)* }
+    ) => {
+        focus_api! {
+            @sync
+            $(#[$impl_meta])*
+            #[cfg(not(target_arch = "wasm32"))]
+            impl $sync_type { $($methods)* }
+        }
+
+        focus_api! {
+            @async
+            $(#[$impl_meta])*
+            #[cfg(all(feature = "async", not(target_arch = "wasm32")))]
+            impl $async_type { $($methods)* }
+        }
+
+        focus_api! {
+            @async
+            $(#[$impl_meta])*
+            #[cfg(target_arch = "wasm32")]
+            impl $sync_type { $($methods)* }
+        }
+    };
+}
+
+/// Transport methods
+#[cfg(not(target_arch = "wasm32"))]
 impl Focus {
-    /// Writes bytes to the serial port.
+    /// Writes bytes to the transport.
     pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), FocusError> {
         trace!("Writing bytes: {:02X?}", bytes);
-        let serial = &mut self.serial;
-        serial
+        let transport = &mut self.transport;
+        transport
             .write_all(bytes)
             .map_err(FocusError::SerialPortWriteError)?;
-        serial.flush().map_err(FocusError::SerialPortFlushError)?;
+        transport
+            .flush()
+            .map_err(FocusError::SerialPortFlushError)?;
         Ok(())
     }
 
-    /// Response from serial port
+    /// Response from the transport
+    ///
+    /// Waits until the response terminator arrives, the command timeout passes, or the read is cancelled.
+    /// Without a command timeout, the read fails once the transport has been silent for its read timeout.
+    ///
+    /// Bytes after the terminator are kept for the next call, as they belong to the next pipelined response.
     pub fn read_string(&mut self) -> Result<String, FocusError> {
-        let eof_marker = b"\r\n.\r\n";
-        self.response_buffer.clear();
-        let serial = &mut self.serial;
-        loop {
-            let prev_len = self.response_buffer.len();
-            self.response_buffer.resize(prev_len + 1024, 0);
-            match serial.read(&mut self.response_buffer[prev_len..]) {
-                Ok(0) => continue,
+        self.read_response().map(str::to_string)
+    }
+
+    /// Response from the transport, borrowed from the response buffer until the next read.
+    ///
+    /// See `read_string` for how long it waits.
+    pub fn read_response(&mut self) -> Result<&str, FocusError> {
+        let frame = self.read_frame()?;
+        std::str::from_utf8(frame).map_err(FocusError::Utf8ConversionError)
+    }
+
+    /// Gets the deadline for the whole response of a command.
+    pub fn command_timeout(&self) -> Option<Duration> {
+        self.command_timeout
+    }
+
+    /// Sets the deadline for the whole response of a command, `None` waits for as long as data keeps arriving.
+    ///
+    /// The deadline is checked between reads, so it is only as precise as the read timeout.
+    pub fn set_command_timeout(&mut self, timeout: Option<Duration>) {
+        self.command_timeout = timeout;
+    }
+
+    /// Gets how long a single read from the transport may block.
+    pub fn read_timeout(&self) -> Duration {
+        self.transport.timeout()
+    }
+
+    /// Sets how long a single read from the transport may block.
+    ///
+    /// This also bounds how quickly a cancellation is noticed.
+    pub fn set_read_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        self.transport.set_timeout(timeout)
+    }
+
+    /// Gets a handle that can cancel a pending read from another thread.
+    pub fn cancel_handle(&self) -> CancelHandle {
+        self.cancel.clone()
+    }
+
+    /// Gives up the Focus API and returns the transport it was talking over.
+    ///
+    /// Bytes already read but not yet taken as a response are dropped.
+    pub fn into_transport(self) -> Box<dyn Transport> {
+        self.transport
+    }
+
+    /// Reads until the framer holds a complete response, then takes it.
+    fn read_frame(&mut self) -> Result<&[u8], FocusError> {
+        let started = Instant::now();
+        let mut last_activity = started;
+        let mut chunk = [0u8; 1024];
+        while !self.framer.has_frame() {
+            if self.cancel.take() {
+                return Err(FocusError::Cancelled {
+                    command: self.command.clone(),
+                });
+            }
+            let timed_out = match self.transport.read(&mut chunk) {
+                Ok(0) => {
+                    return Err(FocusError::SerialPortReadError(
+                        ErrorKind::UnexpectedEof.into(),
+                    ))
+                }
                 Ok(size) => {
-                    self.response_buffer.truncate(prev_len + size);
-                    self.response_buffer.retain(|&x| x != 0);
-                    trace!("Received bytes: {:02X?}", &self.response_buffer[..size]);
-                    if self.response_buffer.ends_with(eof_marker) {
-                        break;
-                    }
+                    trace!("Received bytes: {:02X?}", &chunk[..size]);
+                    self.framer.push(&chunk[..size]);
+                    last_activity = Instant::now();
+                    false
                 }
-                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) if e.kind() == ErrorKind::TimedOut => true,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => false,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
                 Err(e) => return Err(FocusError::SerialPortReadError(e)),
+            };
+            let expired = match self.command_timeout {
+                Some(deadline) => started.elapsed() >= deadline,
+                None => timed_out || last_activity.elapsed() >= self.transport.timeout(),
+            };
+            if expired {
+                return Err(FocusError::Timeout {
+                    command: self.command.clone(),
+                    elapsed: started.elapsed(),
+                });
             }
         }
-        while let Some(pos) = self
-            .response_buffer
-            .windows(eof_marker.len())
-            .position(|window| window == eof_marker)
-        {
-            self.response_buffer.drain(pos..pos + eof_marker.len());
-        }
-        let start = self
-            .response_buffer
-            .iter()
-            .position(|&b| !b.is_ascii_whitespace())
-            .unwrap_or(0);
-        let end = self
-            .response_buffer
-            .iter()
-            .rposition(|&b| !b.is_ascii_whitespace())
-            .map_or(0, |p| p + 1);
-        let trimmed_buffer = &self.response_buffer[start..end];
-        let response =
-            std::str::from_utf8(trimmed_buffer).map_err(FocusError::Utf8ConversionError)?;
-        if !response.is_empty() {
-            trace!("Command RX: {}", &response);
+        let frame = self.framer.next_frame().unwrap_or_default();
+        if let Some(e) = self.transport.response_error(&self.command, frame) {
+            return Err(e);
+        }
+        if !frame.is_empty() {
+            trace!("Command RX: {}", String::from_utf8_lossy(frame));
         } else {
             trace!("Command RX: [Ack]");
         }
-        Ok(response.to_string())
+        Ok(frame)
     }
+}
 
-    /// Gets the settings from the device.
-    pub fn settings_get(&mut self) -> Result<Settings, FocusError> {
-        Ok(Settings {
-            keymap_custom: self.keymap_custom_get()?,
-            keymap_default: self.keymap_default_get()?,
-            keymap_only_custom: self.keymap_only_custom_get()?,
-            settings_default_layer: self.settings_default_layer_get()?,
-            superkeys_map: self.superkeys_map_get()?,
-            superkeys_wait_for: self.superkeys_wait_for_get()?,
-            superkeys_timeout: self.superkeys_timeout_get()?,
-            superkeys_repeat: self.superkeys_repeat_get()?,
-            superkeys_hold_start: self.superkeys_hold_start_get()?,
-            superkeys_overlap: self.superkeys_overlap_get()?,
-            led_mode: self.led_mode_get()?,
-            led_brightness_keys_wired: self.led_brightness_top_get()?,
-            led_brightness_underglow_wired: self.led_brightness_underglow_wired_get().ok(),
-            led_brightness_keys_wireless: self.led_brightness_keys_wireless_get().ok(),
-            led_brightness_underglow_wireless: self.led_brightness_underglow_wireless_get().ok(),
-            led_fade: self.led_fade_get().ok(),
-            led_theme: self.led_theme_get()?,
-            palette_rgb: self.palette_rgb_get().ok(),
-            palette_rgbw: self.palette_rgbw_get().ok(),
-            color_map: self.color_map_get()?,
-            led_idle_true_sleep: self.led_idle_true_sleep_get().ok(),
-            led_idle_true_sleep_time: self.led_idle_true_sleep_time_get().ok(),
-            led_idle_time_limit_wired: self.led_idle_time_limit_wired_get()?,
-            led_idle_time_limit_wireless: self.led_idle_time_limit_wireless_get().ok(),
-            qukeys_hold_timeout: self.qukeys_hold_timeout_get()?,
-            qukeys_overlap_threshold: self.qukeys_overlap_threshold_get()?,
-            macros_map: self.macros_map_get()?,
-            mouse_speed: self.mouse_speed_get()?,
-            mouse_delay: self.mouse_delay_get()?,
-            mouse_acceleration_speed: self.mouse_acceleration_speed_get()?,
-            mouse_acceleration_delay: self.mouse_acceleration_delay_get()?,
-            mouse_wheel_speed: self.mouse_wheel_speed_get()?,
-            mouse_wheel_delay: self.mouse_wheel_delay_get()?,
-            mouse_speed_limit: self.mouse_speed_limit_get()?,
-            wireless_battery_saving_mode: self.wireless_battery_saving_mode_get().ok(),
-            wireless_rf_power_level: self.wireless_rf_power_level_get().ok(),
-            wireless_rf_channel_hop: self.wireless_rf_channel_hop_get().ok(),
-        })
-    }
+focus_api! {
+    /// Public methods
+    impl Focus, AsyncFocus {
+        /// Sends the commands back to back without waiting in between, then returns their responses in order.
+        ///
+        /// This saves a round trip per command, which adds up on wireless devices.
+        pub fn batch<S: AsRef<str>>(&mut self, commands: &[S]) -> Result<Vec<String>, FocusError> {
+            let mut payload = String::new();
+            for command in commands {
+                trace!("Command TX: {}", command.as_ref());
+                payload.push_str(command.as_ref());
+                payload.push('\n');
+            }
+            self.framer.clear();
+            io!(self.write_bytes(payload.as_bytes()))?;
+
+            let mut responses = Vec::with_capacity(commands.len());
+            for command in commands {
+                self.command.clear();
+                self.command.push_str(command.as_ref());
+                responses.push(io!(self.read_string())?);
+            }
+            Ok(responses)
+        }
 
-    /// Sets the settings for the device.
-    pub fn settings_set(&mut self, settings: &Settings) -> Result<(), FocusError> {
-        self.keymap_custom_set(&settings.keymap_custom)?;
-        self.keymap_default_set(&settings.keymap_default)?;
-        self.keymap_only_custom_set(settings.keymap_only_custom)?;
-        self.settings_default_layer_set(settings.settings_default_layer)?;
-        self.superkeys_map_set(&settings.superkeys_map)?;
-        self.superkeys_wait_for_set(settings.superkeys_wait_for)?;
-        self.superkeys_timeout_set(settings.superkeys_timeout)?;
-        self.superkeys_repeat_set(settings.superkeys_repeat)?;
-        self.superkeys_hold_start_set(settings.superkeys_hold_start)?;
-        self.superkeys_overlap_set(settings.superkeys_overlap)?;
-        self.led_mode_set(settings.led_mode)?;
-        self.led_brightness_top_set(settings.led_brightness_keys_wired)?;
-        if let Some(value) = settings.led_brightness_underglow_wired {
-            self.led_brightness_underglow_wired_set(value)?;
-        }
-        if let Some(value) = settings.led_brightness_keys_wireless {
-            self.led_brightness_keys_wireless_set(value)?;
-        }
-        if let Some(value) = settings.led_brightness_underglow_wireless {
-            self.led_brightness_underglow_wireless_set(value)?;
-        }
-        if let Some(value) = settings.led_fade {
-            self.led_fade_set(value)?;
-        }
-        self.led_theme_set(&settings.led_theme)?;
-        if let Some(value) = &settings.palette_rgb {
-            self.palette_rgb_set(value)?;
-        }
-        if let Some(value) = &settings.palette_rgbw {
-            self.palette_rgbw_set(value)?;
-        }
-        self.color_map_set(&settings.color_map)?;
-        if let Some(value) = settings.led_idle_true_sleep {
-            self.led_idle_true_sleep_set(value)?;
-        }
-        if let Some(value) = settings.led_idle_true_sleep_time {
-            self.led_idle_true_sleep_time_set(value)?;
-        }
-        self.led_idle_time_limit_wired_set(settings.led_idle_time_limit_wired)?;
-        if let Some(value) = settings.led_idle_time_limit_wireless {
-            self.led_idle_time_limit_wireless_set(value)?;
-        }
-        self.qukeys_hold_timeout_set(settings.qukeys_hold_timeout)?;
-        self.qukeys_overlap_threshold_set(settings.qukeys_overlap_threshold)?;
-        self.macros_map_set(&settings.macros_map)?;
-        self.mouse_speed_set(settings.mouse_speed)?;
-        self.mouse_delay_set(settings.mouse_delay)?;
-        self.mouse_acceleration_speed_set(settings.mouse_acceleration_speed)?;
-        self.mouse_acceleration_delay_set(settings.mouse_acceleration_delay)?;
-        self.mouse_wheel_speed_set(settings.mouse_wheel_speed)?;
-        self.mouse_wheel_delay_set(settings.mouse_wheel_delay)?;
-        self.mouse_speed_limit_set(settings.mouse_speed_limit)?;
-        if let Some(wireless_battery_saving_mode) = settings.wireless_battery_saving_mode {
-            self.wireless_battery_saving_mode_set(wireless_battery_saving_mode)?;
-        }
-        if let Some(wireless_rf_power_level) = settings.wireless_rf_power_level {
-            self.wireless_rf_power_level_set(wireless_rf_power_level)?;
-        }
-        if let Some(wireless_rf_channel_hop) = settings.wireless_rf_channel_hop {
-            self.wireless_rf_channel_hop_set(wireless_rf_channel_hop)?;
+        /// Sends a command to the device and waits for it to be acknowledged.
+        ///
+        /// Use this for commands the crate does not wrap yet.
+        pub fn send(&mut self, command: &str) -> Result<(), FocusError> {
+            io!(self.command_new_line(command, true))
         }
 
-        Ok(())
+        /// Sends a command to the device and decodes the response.
+        ///
+        /// Use this for commands the crate does not wrap yet, for example `focus.query::<String>("upgrade.keyscanner.validate")`.
+        pub fn query<T: FromFocusResponse>(&mut self, command: &str) -> Result<T, FocusError> {
+            let response = io!(self.command_response_string(command))?;
+            T::from_focus_response(&response)
+        }
+
+        /// Gets the settings from the device.
+        pub fn settings_get(&mut self) -> Result<Settings, FocusError> {
+            let responses = io!(self.batch(&SETTINGS_GET_COMMANDS))?;
+            Settings::from_responses(responses)
+        }
+
+        /// Sets the settings for the device.
+        ///
+        /// Every value is validated before anything is sent.
+        pub fn settings_set(&mut self, settings: &Settings) -> Result<(), FocusError> {
+            let commands = settings.to_commands()?;
+            io!(self.batch(&commands))?;
+            Ok(())
+        }
     }
 }
 
-/// Private methods
-impl Focus {
-    /// Sends a command to the device.
-    fn command_raw(
-        &mut self,
-        command: &str,
-        suffix: Option<char>,
-        wait_for_response: bool,
-    ) -> Result<(), FocusError> {
-        trace!("Command TX: {}", command);
-
-        if let Some(char) = suffix {
-            self.write_bytes(format!("{}{}", command, char).as_bytes())?;
-        } else {
-            self.write_bytes(command.as_bytes())?;
-        }
+focus_api! {
+    /// Private methods
+    impl Focus, AsyncFocus {
+        /// Sends a command to the device.
+        fn command_raw(
+            &mut self,
+            command: &str,
+            suffix: Option<char>,
+            wait_for_response: bool,
+        ) -> Result<(), FocusError> {
+            trace!("Command TX: {}", command);
+            self.command.clear();
+            self.command.push_str(command);
+            self.framer.clear();
+
+            if let Some(char) = suffix {
+                io!(self.write_bytes(format!("{}{}", command, char).as_bytes()))?;
+            } else {
+                io!(self.write_bytes(command.as_bytes()))?;
+            }
+
+            if wait_for_response {
+                let _response = io!(self.read_string())?;
+                // It's not necessary to do anything with the response, but we need to wait for it.
+            }
 
-        if wait_for_response {
-            let _response = self.read_string()?;
-            // It's not necessary to do anything with the response, but we need to wait for it.
+            Ok(())
         }
 
-        Ok(())
-    }
+        /// Sends a command to the device, with a single new line ending.
+        fn command_new_line(
+            &mut self,
+            command: &str,
+            wait_for_response: bool,
+        ) -> Result<(), FocusError> {
+            io!(self.command_raw(command, Some('\n'), wait_for_response))
+        }
 
-    /// Sends a command to the device, with a single new line ending.
-    fn command_new_line(
-        &mut self,
-        command: &str,
-        wait_for_response: bool,
-    ) -> Result<(), FocusError> {
-        self.command_raw(command, Some('\n'), wait_for_response)
-    }
+        /// Sends a command to the device, with a single whitespace ending.
+        fn command_whitespace(&mut self, command: &str) -> Result<(), FocusError> {
+            io!(self.command_raw(command, Some(' '), false))
+        }
 
-    /// Sends a command to the device, with a single whitespace ending.
-    fn command_whitespace(&mut self, command: &str) -> Result<(), FocusError> {
-        self.command_raw(command, Some(' '), false)
-    }
+        /// Sends a command to the device, and returns the response as a string.
+        fn command_response_string(&mut self, command: &str) -> Result<String, FocusError> {
+            io!(self.command_new_line(command, false))?;
+            io!(self.read_string())
+        }
 
-    /// Sends a command to the device, and returns the response as a string.
-    fn command_response_string(&mut self, command: &str) -> Result<String, FocusError> {
-        self.command_new_line(command, false)?;
-        self.read_string()
-    }
+        /// Sends a command to the device, and returns the response as a numerical value.
+        fn command_response_numerical<T: FromStr>(
+            &mut self,
+            command: &str,
+        ) -> Result<T, FocusError> {
+            let response = io!(self.command_response_string(command))?;
+            response
+                .parse::<T>()
+                .map_err(|_| FocusError::ParseNumericalError { string: response })
+        }
 
-    /// Sends a command to the device, and returns the response as a numerical value.
-    fn command_response_numerical<T>(&mut self, command: &str) -> Result<T, FocusError>
-    where
-        T: FromStr,
-        <T as FromStr>::Err: std::fmt::Debug,
-    {
-        let response = self.command_response_string(command)?;
-        response
-            .parse::<T>()
-            .map_err(|_| FocusError::ParseNumericalError { string: response })
-    }
+        /// Sends a command to the device, and parses the response straight into a vector of numbers.
+        fn command_response_numerical_vec<T: TryFrom<u64>>(
+            &mut self,
+            command: &str,
+        ) -> Result<Vec<T>, FocusError> {
+            io!(self.command_new_line(command, false))?;
+            parse_numerical_vec(io!(self.read_frame())?)
+        }
 
-    /// Sends a command to the device, and returns the response as a boolean value.
-    fn command_response_bool(&mut self, command: &str) -> Result<bool, FocusError> {
-        let response = self.command_response_string(command)?;
-        if response.is_empty() {
-            Err(FocusError::EmptyResponseError)
-        } else if response == "0" || response == "false" {
-            Ok(false)
-        } else if response == "1" || response == "true" {
-            Ok(true)
-        } else {
-            return Err(FocusError::ParseBoolError { string: response });
+        /// Sends a command to the device, and returns the response as a boolean value.
+        fn command_response_bool(&mut self, command: &str) -> Result<bool, FocusError> {
+            io!(self.query(command))
         }
-    }
 
-    /// Sends a command to the device, and returns the response as a vector of strings.
-    fn command_response_vec_string(&mut self, command: &str) -> Result<Vec<String>, FocusError> {
-        Ok(self
-            .command_response_string(command)?
-            .lines()
-            .map(|line| line.replace('\r', ""))
-            .collect())
+        /// Sends a command to the device, and returns the response as a vector of strings.
+        fn command_response_vec_string(
+            &mut self,
+            command: &str,
+        ) -> Result<Vec<String>, FocusError> {
+            io!(self.query(command))
+        }
     }
 }
 
-/// Public API methods
-impl Focus {
-    /// Get the version of the firmware.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#version
-    pub fn version(&mut self) -> Result<String, FocusError> {
-        self.command_response_string("version")
-    }
+focus_api! {
+    /// Public API methods
+    impl Focus, AsyncFocus {
+        /// Get the version of the firmware.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#version
+        pub fn version(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("version"))
+        }
 
-    /// Gets the whole custom keymap stored in the keyboard.
-    ///
-    /// Layers 0 and above, The layers are -1 to Bazecor.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymapcustom
-    pub fn keymap_custom_get(&mut self) -> Result<Vec<u16>, FocusError> {
-        let data = self.command_response_string("keymap.custom")?;
-        string_to_numerical_vec(&data)
-    }
+        /// Gets the whole custom keymap stored in the keyboard.
+        ///
+        /// Layers 0 and above, The layers are -1 to Bazecor.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymapcustom
+        pub fn keymap_custom_get(&mut self) -> Result<Vec<u16>, FocusError> {
+            io!(self.command_response_numerical_vec("keymap.custom"))
+        }
 
-    /// Sets the whole custom keymap stored in the keyboard.
-    ///
-    /// Layers 0 and above, The layers are -1 to Bazecor.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymapcustom
-    pub fn keymap_custom_set(&mut self, data: &[u16]) -> Result<(), FocusError> {
-        self.command_new_line(
-            &format!("keymap.custom {}", numerical_vec_to_string(data)),
-            true,
-        )
-    }
+        /// Sets the whole custom keymap stored in the keyboard.
+        ///
+        /// Layers 0 and above, The layers are -1 to Bazecor.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymapcustom
+        pub fn keymap_custom_set(&mut self, data: &[u16]) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::keymap_custom(data), true))
+        }
 
-    /// Gets the default keymap stored in the keyboard.
-    ///
-    /// Layers -1 and -2, the layers are -1 to Bazecor.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymapdefault
-    pub fn keymap_default_get(&mut self) -> Result<Vec<u16>, FocusError> {
-        let data = self.command_response_string("keymap.default")?;
-        string_to_numerical_vec(&data)
-    }
+        /// Gets the default keymap stored in the keyboard.
+        ///
+        /// Layers -1 and -2, the layers are -1 to Bazecor.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymapdefault
+        pub fn keymap_default_get(&mut self) -> Result<Vec<u16>, FocusError> {
+            io!(self.command_response_numerical_vec("keymap.default"))
+        }
 
-    /// Sets the default keymap stored in the keyboard.
-    ///
-    /// Layers -1 and -2, the layers are -1 to Bazecor.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymapdefault
-    pub fn keymap_default_set(&mut self, data: &[u16]) -> Result<(), FocusError> {
-        self.command_new_line(
-            &format!("keymap.default {}", numerical_vec_to_string(data)),
-            true,
-        )
-    }
+        /// Sets the default keymap stored in the keyboard.
+        ///
+        /// Layers -1 and -2, the layers are -1 to Bazecor.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymapdefault
+        pub fn keymap_default_set(&mut self, data: &[u16]) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::keymap_default(data), true))
+        }
 
-    /// Gets the user setting of hiding the default layers.
-    ///
-    /// It does not allow you to increment the number of available layers by start using the default ones.
-    /// They are there so you can store a backup for two layers in your keyboard.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymaponlycustom
-    pub fn keymap_only_custom_get(&mut self) -> Result<bool, FocusError> {
-        self.command_response_bool("keymap.onlyCustom")
-    }
+        /// Gets the user setting of hiding the default layers.
+        ///
+        /// It does not allow you to increment the number of available layers by start using the default ones.
+        /// They are there so you can store a backup for two layers in your keyboard.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymaponlycustom
+        pub fn keymap_only_custom_get(&mut self) -> Result<bool, FocusError> {
+            io!(self.command_response_bool("keymap.onlyCustom"))
+        }
 
-    /// Sets the user setting of hiding the default layers.
-    ///
-    /// It does not allow you to increment the number of available layers by start using the default ones.
-    /// They are there so you can store a backup for two layers in your keyboard.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymaponlycustom
-    pub fn keymap_only_custom_set(&mut self, state: bool) -> Result<(), FocusError> {
-        self.command_new_line(&format!("keymap.onlyCustom {}", state as u8), true)
-    }
+        /// Sets the user setting of hiding the default layers.
+        ///
+        /// It does not allow you to increment the number of available layers by start using the default ones.
+        /// They are there so you can store a backup for two layers in your keyboard.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#keymaponlycustom
+        pub fn keymap_only_custom_set(&mut self, state: bool) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::keymap_only_custom(state), true))
+        }
 
-    /// Gets the default layer the keyboard will boot with.
-    ///
-    /// The layer is -1 to Bazecor.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingsdefaultlayer
-    pub fn settings_default_layer_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("settings.defaultLayer")
-    }
+        /// Gets the default layer the keyboard will boot with.
+        ///
+        /// The layer is -1 to Bazecor.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingsdefaultlayer
+        pub fn settings_default_layer_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("settings.defaultLayer"))
+        }
 
-    /// Sets the default layer the keyboard will boot with.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingsdefaultlayer
-    pub fn settings_default_layer_set(&mut self, layer: u8) -> Result<(), FocusError> {
-        if layer > MAX_LAYERS {
-            return Err(FocusError::ValueAboveLimitError {
-                label: "layer",
-                max: MAX_LAYERS as usize,
-                provided: layer as usize,
-            });
-        }
-        if self.settings_default_layer_get()? == layer {
-            return Ok(());
-        }
-        self.command_new_line(&format!("settings.defaultLayer {}", layer), true)
-    }
+        /// Sets the default layer the keyboard will boot with.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingsdefaultlayer
+        pub fn settings_default_layer_set(&mut self, layer: u8) -> Result<(), FocusError> {
+            let command = commands::settings_default_layer(layer)?;
+            if io!(self.settings_default_layer_get())? == layer {
+                return Ok(());
+            }
+            io!(self.command_new_line(&command, true))
+        }
 
-    /// Gets a boolean value that states true if all checks have been performed on the current settings, and its upload was done in the intended way.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingsvalid
-    pub fn settings_valid(&mut self) -> Result<bool, FocusError> {
-        self.command_response_numerical("settings.valid?")
-    }
+        /// Gets a boolean value that states true if all checks have been performed on the current settings, and its upload was done in the intended way.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingsvalid
+        pub fn settings_valid(&mut self) -> Result<bool, FocusError> {
+            io!(self.command_response_numerical("settings.valid?"))
+        }
 
-    /// Gets the current settings version.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingsversion
-    pub fn settings_version_get(&mut self) -> Result<String, FocusError> {
-        self.command_response_string("settings.version")
-    }
+        /// Gets the current settings version.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingsversion
+        pub fn settings_version_get(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("settings.version"))
+        }
 
-    /// Sets the current settings version.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingsversion
-    pub fn settings_version_set(&mut self, version: &str) -> Result<(), FocusError> {
-        self.command_new_line(&format!("settings.version {}", version), true)
-    }
+        /// Sets the current settings version.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingsversion
+        pub fn settings_version_set(&mut self, version: &str) -> Result<(), FocusError> {
+            io!(self.command_new_line(&format!("settings.version {}", version), true))
+        }
 
-    /// Gets the CRC checksum of the layout.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingscrc
-    pub fn settings_crc(&mut self) -> Result<String, FocusError> {
-        self.command_response_string("settings.crc")
-    }
+        /// Gets the CRC checksum of the layout.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#settingscrc
+        pub fn settings_crc(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("settings.crc"))
+        }
 
-    /// Gets the EEPROM's contents.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#eepromcontents
-    pub fn eeprom_contents_get(&mut self) -> Result<String, FocusError> {
-        self.command_response_string("eeprom.contents")
-    }
+        /// Gets the EEPROM's contents.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#eepromcontents
+        pub fn eeprom_contents_get(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("eeprom.contents"))
+        }
 
-    /// Sets the EEPROM's contents.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#eepromcontents
-    pub fn eeprom_contents_set(&mut self, data: &str) -> Result<(), FocusError> {
-        self.command_new_line(&format!("eeprom.contents {}", data), true)
-    }
+        /// Sets the EEPROM's contents.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#eepromcontents
+        pub fn eeprom_contents_set(&mut self, data: &str) -> Result<(), FocusError> {
+            io!(self.command_new_line(&format!("eeprom.contents {}", data), true))
+        }
 
-    /// Gets the EEPROM's free bytes.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#eepromfree
-    pub fn eeprom_free(&mut self) -> Result<String, FocusError> {
-        self.command_response_string("eeprom.free")
-    }
+        /// Gets the EEPROM's free bytes.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#eepromfree
+        pub fn eeprom_free(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("eeprom.free"))
+        }
 
-    pub fn upgrade_start(&mut self) -> Result<(), FocusError> {
-        self.command_new_line("upgrade.start", false)
-    }
+        pub fn upgrade_start(&mut self) -> Result<(), FocusError> {
+            io!(self.command_new_line("upgrade.start", false))
+        }
 
-    pub fn upgrade_is_ready(&mut self) -> Result<bool, FocusError> {
-        self.command_response_bool("upgrade.isReady")
-    }
+        pub fn upgrade_is_ready(&mut self) -> Result<bool, FocusError> {
+            io!(self.command_response_bool("upgrade.isReady"))
+        }
 
-    pub fn upgrade_neuron(&mut self) -> Result<(), FocusError> {
-        self.command_new_line("upgrade.neuron", false)
-    }
+        pub fn upgrade_neuron(&mut self) -> Result<(), FocusError> {
+            io!(self.command_new_line("upgrade.neuron", false))
+        }
 
-    pub fn upgrade_end(&mut self) -> Result<(), FocusError> {
-        self.command_new_line("upgrade.start", false)
-    }
+        pub fn upgrade_end(&mut self) -> Result<(), FocusError> {
+            io!(self.command_new_line("upgrade.start", false))
+        }
 
-    pub fn upgrade_keyscanner_is_connected(&mut self, side: Side) -> Result<bool, FocusError> {
-        self.command_response_bool(&format!("upgrade.keyscanner.isConnected {}", side as u8))
-    }
+        pub fn upgrade_keyscanner_is_connected(&mut self, side: Side) -> Result<bool, FocusError> {
+            let command = format!("upgrade.keyscanner.isConnected {}", side as u8);
+            io!(self.command_response_bool(&command))
+        }
 
-    pub fn upgrade_keyscanner_is_bootloader(&mut self, side: Side) -> Result<bool, FocusError> {
-        self.command_response_bool(&format!("upgrade.keyscanner.isBootloader {}", side as u8))
-    }
+        pub fn upgrade_keyscanner_is_bootloader(&mut self, side: Side) -> Result<bool, FocusError> {
+            let command = format!("upgrade.keyscanner.isBootloader {}", side as u8);
+            io!(self.command_response_bool(&command))
+        }
 
-    pub fn upgrade_keyscanner_begin(&mut self, side: Side) -> Result<bool, FocusError> {
-        self.command_response_bool(&format!("upgrade.keyscanner.begin {}", side as u8))
-            .map_err(|_| FocusError::SideDisconnectedError { side })
-    }
+        pub fn upgrade_keyscanner_begin(&mut self, side: Side) -> Result<bool, FocusError> {
+            io!(self.command_response_bool(&format!("upgrade.keyscanner.begin {}", side as u8)))
+                .map_err(|_| FocusError::SideDisconnectedError { side })
+        }
 
-    pub fn upgrade_keyscanner_is_ready(&mut self) -> Result<bool, FocusError> {
-        self.command_response_bool("upgrade.keyscanner.isReady")
-            .map_err(|_| FocusError::DeviceNotReadyError)
-    }
+        pub fn upgrade_keyscanner_is_ready(&mut self) -> Result<bool, FocusError> {
+            io!(self.command_response_bool("upgrade.keyscanner.isReady"))
+                .map_err(|_| FocusError::DeviceNotReadyError)
+        }
 
-    pub fn upgrade_keyscanner_get_info(&mut self) -> Result<String, FocusError> {
-        self.command_response_string("upgrade.keyscanner.getInfo")
-    }
+        pub fn upgrade_keyscanner_get_info(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("upgrade.keyscanner.getInfo"))
+        }
 
-    pub fn upgrade_keyscanner_send_write(&mut self) -> Result<(), FocusError> {
-        self.command_whitespace("upgrade.keyscanner.sendWrite")
-    }
+        pub fn upgrade_keyscanner_send_write(&mut self) -> Result<(), FocusError> {
+            io!(self.command_whitespace("upgrade.keyscanner.sendWrite"))
+        }
 
-    // TODO: upgrade.keyscanner.validate
+        // TODO: upgrade.keyscanner.validate
 
-    pub fn upgrade_keyscanner_finish(&mut self) -> Result<String, FocusError> {
-        self.command_response_string("upgrade.keyscanner.finish")
-    }
+        pub fn upgrade_keyscanner_finish(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("upgrade.keyscanner.finish"))
+        }
 
-    // TODO: upgrade.keyscanner.sendStart
+        // TODO: upgrade.keyscanner.sendStart
+
+        /// Gets the Superkeys map.
+        ///
+        /// Each action in a Superkey is represented by a key code number that encodes the action, for example if you use the number 44, you are encoding space, etc...
+        ///
+        /// To know more about keycodes and to find the right one for your actions, check the key map database.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysmap
+        pub fn superkeys_map_get(&mut self) -> Result<Vec<u16>, FocusError> {
+            io!(self.command_response_numerical_vec("superkeys.map"))
+        }
 
-    /// Gets the Superkeys map.
-    ///
-    /// Each action in a Superkey is represented by a key code number that encodes the action, for example if you use the number 44, you are encoding space, etc...
-    ///
-    /// To know more about keycodes and to find the right one for your actions, check the key map database.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysmap
-    pub fn superkeys_map_get(&mut self) -> Result<Vec<u16>, FocusError> {
-        let data = self.command_response_string("superkeys.map")?;
-        string_to_numerical_vec(&data)
-    }
+        /// Sets the Superkeys map.
+        ///
+        /// Each action in a Superkey is represented by a key code number that encodes the action, for example if you use the number 44, you are encoding space, etc...
+        ///
+        /// To know more about keycodes and to find the right one for your actions, check the key map database.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysmap
+        pub fn superkeys_map_set(&mut self, data: &[u16]) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::superkeys_map(data), true))
+        }
 
-    /// Sets the Superkeys map.
-    ///
-    /// Each action in a Superkey is represented by a key code number that encodes the action, for example if you use the number 44, you are encoding space, etc...
-    ///
-    /// To know more about keycodes and to find the right one for your actions, check the key map database.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysmap
-    pub fn superkeys_map_set(&mut self, data: &[u16]) -> Result<(), FocusError> {
-        self.command_new_line(
-            &format!("superkeys.map {}", numerical_vec_to_string(data)),
-            true,
-        )
-    }
+        /// Gets the Superkeys wait for duration in milliseconds.
+        ///
+        /// Wait for value specifies the time between the first and subsequent releases of the HOLD actions meanwhile is held,
+        ///
+        /// So for example,
+        /// if the variable is set to 500ms, you can maintain the hold key, it will emit a key code corresponding to the action that it triggers,
+        /// then it will wait for wait for time for making another key press with that same key code.
+        /// This enables the user to delay the hold "machinegun" to be able to release the key and achieve a single keypress from a hold action.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeyswaitfor
+        pub fn superkeys_wait_for_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("superkeys.waitfor"))
+        }
 
-    /// Gets the Superkeys wait for duration in milliseconds.
-    ///
-    /// Wait for value specifies the time between the first and subsequent releases of the HOLD actions meanwhile is held,
-    ///
-    /// So for example,
-    /// if the variable is set to 500ms, you can maintain the hold key, it will emit a key code corresponding to the action that it triggers,
-    /// then it will wait for wait for time for making another key press with that same key code.
-    /// This enables the user to delay the hold "machinegun" to be able to release the key and achieve a single keypress from a hold action.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeyswaitfor
-    pub fn superkeys_wait_for_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("superkeys.waitfor")
-    }
+        /// Sets the Superkeys wait for duration in milliseconds.
+        ///
+        /// Wait for value specifies the time between the first and subsequent releases of the HOLD actions meanwhile is held,
+        ///
+        /// So for example,
+        /// if the variable is set to 500ms, you can maintain the hold key, it will emit a key code corresponding to the action that it triggers,
+        /// then it will wait for wait for time for making another key press with that same key code.
+        /// This enables the user to delay the hold "machinegun" to be able to release the key and achieve a single keypress from a hold action.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeyswaitfor
+        pub fn superkeys_wait_for_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::superkeys_wait_for(milliseconds), true))
+        }
 
-    /// Sets the Superkeys wait for duration in milliseconds.
-    ///
-    /// Wait for value specifies the time between the first and subsequent releases of the HOLD actions meanwhile is held,
-    ///
-    /// So for example,
-    /// if the variable is set to 500ms, you can maintain the hold key, it will emit a key code corresponding to the action that it triggers,
-    /// then it will wait for wait for time for making another key press with that same key code.
-    /// This enables the user to delay the hold "machinegun" to be able to release the key and achieve a single keypress from a hold action.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeyswaitfor
-    pub fn superkeys_wait_for_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
-        self.command_new_line(&format!("superkeys.waitfor {}", &milliseconds), true)
-    }
+        /// Gets the Superkeys timeout of how long it waits for the next tap in milliseconds.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeystimeout
+        pub fn superkeys_timeout_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("superkeys.timeout"))
+        }
 
-    /// Gets the Superkeys timeout of how long it waits for the next tap in milliseconds.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeystimeout
-    pub fn superkeys_timeout_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("superkeys.timeout")
-    }
+        /// Sets the Superkeys timeout of how long it waits for the next tap in milliseconds.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeystimeout
+        pub fn superkeys_timeout_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::superkeys_timeout(milliseconds), true))
+        }
 
-    /// Sets the Superkeys timeout of how long it waits for the next tap in milliseconds.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeystimeout
-    pub fn superkeys_timeout_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
-        self.command_new_line(&format!("superkeys.timeout {}", &milliseconds), true)
-    }
+        /// Gets the Superkeys repeat duration in milliseconds.
+        ///
+        /// The repeat value specifies the time between the second and subsequent key code releases when on hold, it only takes effect after the wait for timer has been exceeded.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysrepeat
+        pub fn superkeys_repeat_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("superkeys.repeat"))
+        }
 
-    /// Gets the Superkeys repeat duration in milliseconds.
-    ///
-    /// The repeat value specifies the time between the second and subsequent key code releases when on hold, it only takes effect after the wait for timer has been exceeded.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysrepeat
-    pub fn superkeys_repeat_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("superkeys.repeat")
-    }
+        /// Sets the Superkeys repeat duration in milliseconds.
+        ///
+        /// The repeat value specifies the time between the second and subsequent key code releases when on hold, it only takes effect after the wait for timer has been exceeded.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysrepeat
+        pub fn superkeys_repeat_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::superkeys_repeat(milliseconds), true))
+        }
 
-    /// Sets the Superkeys repeat duration in milliseconds.
-    ///
-    /// The repeat value specifies the time between the second and subsequent key code releases when on hold, it only takes effect after the wait for timer has been exceeded.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysrepeat
-    pub fn superkeys_repeat_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
-        self.command_new_line(&format!("superkeys.repeat {}", &milliseconds), true)
-    }
+        /// Gets the Superkeys hold start duration in milliseconds.
+        ///
+        /// The hold start value specifies the minimum time that has to pass between the first key down and any other action to trigger a hold, if held it will emit a hold action.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysholdstart
+        pub fn superkeys_hold_start_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("superkeys.holdstart"))
+        }
 
-    /// Gets the Superkeys hold start duration in milliseconds.
-    ///
-    /// The hold start value specifies the minimum time that has to pass between the first key down and any other action to trigger a hold, if held it will emit a hold action.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysholdstart
-    pub fn superkeys_hold_start_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("superkeys.holdstart")
-    }
+        /// Sets the Superkeys hold start duration in milliseconds.
+        ///
+        /// The hold start value specifies the minimum time that has to pass between the first key down and any other action to trigger a hold, if held it will emit a hold action.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysholdstart
+        pub fn superkeys_hold_start_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::superkeys_hold_start(milliseconds), true))
+        }
 
-    /// Sets the Superkeys hold start duration in milliseconds.
-    ///
-    /// The hold start value specifies the minimum time that has to pass between the first key down and any other action to trigger a hold, if held it will emit a hold action.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysholdstart
-    pub fn superkeys_hold_start_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
-        self.command_new_line(&format!("superkeys.holdstart {}", &milliseconds), true)
-    }
+        /// Gets the Superkeys overlap percentage.
+        ///
+        /// The overlap value specifies the percentage of overlap when fast typing that is allowed to happen before triggering a hold action to the overlapped key pressed after the superkey.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysoverlap
+        pub fn superkeys_overlap_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("superkeys.overlap"))
+        }
 
-    /// Gets the Superkeys overlap percentage.
-    ///
-    /// The overlap value specifies the percentage of overlap when fast typing that is allowed to happen before triggering a hold action to the overlapped key pressed after the superkey.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysoverlap
-    pub fn superkeys_overlap_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("superkeys.overlap")
-    }
+        /// Sets the Superkeys overlap percentage.
+        ///
+        /// The overlap value specifies the percentage of overlap when fast typing that is allowed to happen before triggering a hold action to the overlapped key pressed after the superkey.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysoverlap
+        pub fn superkeys_overlap_set(&mut self, percentage: u8) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::superkeys_overlap(percentage)?, true))
+        }
 
-    /// Sets the Superkeys overlap percentage.
-    ///
-    /// The overlap value specifies the percentage of overlap when fast typing that is allowed to happen before triggering a hold action to the overlapped key pressed after the superkey.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#superkeysoverlap
-    pub fn superkeys_overlap_set(&mut self, percentage: u8) -> Result<(), FocusError> {
-        if percentage > 80 {
-            return Err(FocusError::ValueAboveLimitError {
-                label: "percentage",
-                max: 80,
-                provided: percentage as usize,
-            });
-        }
-        self.command_new_line(&format!("superkeys.overlap {}", percentage), true)
-    }
+        /// Gets the color of a specific LED.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledat
+        pub fn led_at_get(&mut self, led: u8) -> Result<RGB, FocusError> {
+            let response = io!(self.command_response_string(&format!("led.at {}", led)))?;
+            if response.is_empty() {
+                return Err(FocusError::EmptyResponseError);
+            }
+            let parts = response.split_whitespace().collect::<Vec<&str>>();
+            if parts.len() != 3 {
+                return Err(FocusError::PartCountError { expected: 3 });
+            }
+            let r = parts[0].parse().map_err(FocusError::ParseIntError)?;
+            let g = parts[1].parse().map_err(FocusError::ParseIntError)?;
+            let b = parts[2].parse().map_err(FocusError::ParseIntError)?;
+            Ok(RGB { r, g, b })
+        }
 
-    /// Gets the color of a specific LED.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledat
-    pub fn led_at_get(&mut self, led: u8) -> Result<RGB, FocusError> {
-        let response = self.command_response_string(&format!("led.at {}", led))?;
-        if response.is_empty() {
-            return Err(FocusError::EmptyResponseError);
-        }
-        let parts = response.split_whitespace().collect::<Vec<&str>>();
-        if parts.len() != 3 {
-            return Err(FocusError::PartCountError { expected: 3 });
-        }
-        let r = parts[0].parse().map_err(FocusError::ParseIntError)?;
-        let g = parts[1].parse().map_err(FocusError::ParseIntError)?;
-        let b = parts[2].parse().map_err(FocusError::ParseIntError)?;
-        Ok(RGB { r, g, b })
-    }
+        /// Sets the color of a specific LED.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledat
+        pub fn led_at_set(&mut self, led: u8, color: &RGB) -> Result<(), FocusError> {
+            io!(self.command_new_line(
+                &format!("led.at {} {} {} {}", led, color.r, color.g, color.b),
+                true,
+            ))
+        }
 
-    /// Sets the color of a specific LED.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledat
-    pub fn led_at_set(&mut self, led: u8, color: &RGB) -> Result<(), FocusError> {
-        self.command_new_line(
-            &format!("led.at {} {} {} {}", led, color.r, color.g, color.b),
-            true,
-        )
-    }
+        /// Sets the color of all the LEDs.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledsetall
+        pub fn led_all(&mut self, color: &RGB) -> Result<(), FocusError> {
+            io!(self.command_new_line(
+                &format!("led.setAll {} {} {}", color.r, color.g, color.b,),
+                true,
+            ))
+        }
 
-    /// Sets the color of all the LEDs.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledsetall
-    pub fn led_all(&mut self, color: &RGB) -> Result<(), FocusError> {
-        self.command_new_line(
-            &format!("led.setAll {} {} {}", color.r, color.g, color.b,),
-            true,
-        )
-    }
+        /// Gets the LED mode.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledmode
+        pub fn led_mode_get(&mut self) -> Result<LedMode, FocusError> {
+            io!(self.command_response_numerical("led.mode"))
+        }
 
-    /// Gets the LED mode.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledmode
-    pub fn led_mode_get(&mut self) -> Result<LedMode, FocusError> {
-        self.command_response_numerical("led.mode")
-    }
+        /// Sets the LED mode.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledmode
+        pub fn led_mode_set(&mut self, mode: LedMode) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::led_mode(mode), true))
+        }
 
-    /// Sets the LED mode.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledmode
-    pub fn led_mode_set(&mut self, mode: LedMode) -> Result<(), FocusError> {
-        self.command_new_line(&format!("led.mode {}", mode as u8), true)
-    }
+        /// Gets the key LED brightness (wired).
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightness
+        pub fn led_brightness_top_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("led.brightness"))
+        }
 
-    /// Gets the key LED brightness (wired).
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightness
-    pub fn led_brightness_top_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("led.brightness")
-    }
+        /// Sets the key LED brightness (wired).
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightness
+        pub fn led_brightness_top_set(&mut self, brightness: u8) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::led_brightness_top(brightness), true))
+        }
 
-    /// Sets the key LED brightness (wired).
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightness
-    pub fn led_brightness_top_set(&mut self, brightness: u8) -> Result<(), FocusError> {
-        self.command_new_line(&format!("led.brightness {}", brightness), true)
-    }
+        /// Gets the underglow LED brightness (wired).
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnessug
+        pub fn led_brightness_underglow_wired_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("led.brightnessUG"))
+        }
 
-    /// Gets the underglow LED brightness (wired).
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnessug
-    pub fn led_brightness_underglow_wired_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("led.brightnessUG")
-    }
+        /// Sets the underglow LED brightness (wired).
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnessug
+        pub fn led_brightness_underglow_wired_set(
+            &mut self,
+            brightness: u8,
+        ) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::led_brightness_underglow_wired(brightness), true))
+        }
 
-    /// Sets the underglow LED brightness (wired).
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnessug
-    pub fn led_brightness_underglow_wired_set(&mut self, brightness: u8) -> Result<(), FocusError> {
-        self.command_new_line(&format!("led.brightnessUG {}", brightness), true)
-    }
+        /// Gets the key LED brightness (wireless).
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnesswireless
+        pub fn led_brightness_keys_wireless_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("led.brightness.wireless"))
+        }
 
-    /// Gets the key LED brightness (wireless).
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnesswireless
-    pub fn led_brightness_keys_wireless_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("led.brightness.wireless")
-    }
+        /// Sets the key LED brightness (wireless).
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnesswireless
+        pub fn led_brightness_keys_wireless_set(
+            &mut self,
+            brightness: u8,
+        ) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::led_brightness_keys_wireless(brightness), true))
+        }
 
-    /// Sets the key LED brightness (wireless).
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnesswireless
-    pub fn led_brightness_keys_wireless_set(&mut self, brightness: u8) -> Result<(), FocusError> {
-        self.command_new_line(&format!("led.brightness.wireless {}", brightness), true)
-    }
+        /// Gets the underglow LED brightness (wireless).
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnessugwireless
+        pub fn led_brightness_underglow_wireless_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("led.brightnessUG.wireless"))
+        }
 
-    /// Gets the underglow LED brightness (wireless).
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnessugwireless
-    pub fn led_brightness_underglow_wireless_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("led.brightnessUG.wireless")
-    }
+        /// Sets the underglow LED brightness (wireless).
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnessugwireless
+        pub fn led_brightness_underglow_wireless_set(
+            &mut self,
+            brightness: u8,
+        ) -> Result<(), FocusError> {
+            io!(self.command_new_line(
+                &commands::led_brightness_underglow_wireless(brightness),
+                true
+            ))
+        }
 
-    /// Sets the underglow LED brightness (wireless).
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledbrightnessugwireless
-    pub fn led_brightness_underglow_wireless_set(
-        &mut self,
-        brightness: u8,
-    ) -> Result<(), FocusError> {
-        self.command_new_line(&format!("led.brightnessUG.wireless {}", brightness), true)
-    }
+        /// Gets the LED fade.
+        pub fn led_fade_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("led.fade"))
+        }
 
-    /// Gets the LED fade.
-    pub fn led_fade_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("led.fade")
-    }
+        /// Sets the LED fade.
+        pub fn led_fade_set(&mut self, fade: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::led_fade(fade), true))
+        }
 
-    /// Sets the LED fade.
-    pub fn led_fade_set(&mut self, fade: u16) -> Result<(), FocusError> {
-        self.command_new_line(&format!("led.fade {}", fade), true)
-    }
+        /// Gets the LED theme.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledtheme
+        pub fn led_theme_get(&mut self) -> Result<Vec<RGB>, FocusError> {
+            let data = io!(self.command_response_string("led.theme"))?;
+            string_to_rgb_vec(&data)
+        }
 
-    /// Gets the LED theme.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledtheme
-    pub fn led_theme_get(&mut self) -> Result<Vec<RGB>, FocusError> {
-        let data = self.command_response_string("led.theme")?;
-        string_to_rgb_vec(&data)
-    }
+        /// Sets the LED theme.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledtheme
+        pub fn led_theme_set(&mut self, data: &[RGB]) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::led_theme(data), true))
+        }
 
-    /// Sets the LED theme.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#ledtheme
-    pub fn led_theme_set(&mut self, data: &[RGB]) -> Result<(), FocusError> {
-        self.command_new_line(&format!("led.theme {}", &rgb_vec_to_string(data)), true)
-    }
+        /// Gets the palette as RGB.
+        ///
+        /// The color palette is used by the color map to establish each color that can be assigned to the keyboard.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#palette
+        pub fn palette_rgb_get(&mut self) -> Result<Vec<RGB>, FocusError> {
+            let data = io!(self.command_response_string("palette"))?;
+            string_to_rgb_vec(&data)
+        }
 
-    /// Gets the palette as RGB.
-    ///
-    /// The color palette is used by the color map to establish each color that can be assigned to the keyboard.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#palette
-    pub fn palette_rgb_get(&mut self) -> Result<Vec<RGB>, FocusError> {
-        let data = self.command_response_string("palette")?;
-        string_to_rgb_vec(&data)
-    }
+        /// Sets the palette as RGB.
+        ///
+        /// The color palette is used by the color map to establish each color that can be assigned to the keyboard.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#palette
+        pub fn palette_rgb_set(&mut self, data: &[RGB]) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::palette_rgb(data), true))
+        }
 
-    /// Sets the palette as RGB.
-    ///
-    /// The color palette is used by the color map to establish each color that can be assigned to the keyboard.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#palette
-    pub fn palette_rgb_set(&mut self, data: &[RGB]) -> Result<(), FocusError> {
-        self.command_new_line(&format!("palette {}", rgb_vec_to_string(data)), true)
-    }
+        /// Gets the palette as RGBW.
+        ///
+        /// The color palette is used by the color map to establish each color that can be assigned to the keyboard.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#palette
+        pub fn palette_rgbw_get(&mut self) -> Result<Vec<RGBW>, FocusError> {
+            let data = io!(self.command_response_string("palette"))?;
+            string_to_rgbw_vec(&data)
+        }
 
-    /// Gets the palette as RGBW.
-    ///
-    /// The color palette is used by the color map to establish each color that can be assigned to the keyboard.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#palette
-    pub fn palette_rgbw_get(&mut self) -> Result<Vec<RGBW>, FocusError> {
-        let data = self.command_response_string("palette")?;
-        string_to_rgbw_vec(&data)
-    }
+        /// Sets the palette as RGBW.
+        ///
+        /// The color palette is used by the color map to establish each color that can be assigned to the keyboard.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#palette
+        pub fn palette_rgbw_set(&mut self, data: &[RGBW]) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::palette_rgbw(data), true))
+        }
 
-    /// Sets the palette as RGBW.
-    ///
-    /// The color palette is used by the color map to establish each color that can be assigned to the keyboard.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#palette
-    pub fn palette_rgbw_set(&mut self, data: &[RGBW]) -> Result<(), FocusError> {
-        self.command_new_line(&format!("palette {}", rgbw_vec_to_string(data)), true)
-    }
+        /// Gets the color map.
+        ///
+        /// This command reads the color map that assigns each color listed in the palette to individual LEDs, mapping them to the keyboard's current layout.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#colormapmap
+        pub fn color_map_get(&mut self) -> Result<Vec<u8>, FocusError> {
+            io!(self.command_response_numerical_vec("colormap.map"))
+        }
 
-    /// Gets the color map.
-    ///
-    /// This command reads the color map that assigns each color listed in the palette to individual LEDs, mapping them to the keyboard's current layout.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#colormapmap
-    pub fn color_map_get(&mut self) -> Result<Vec<u8>, FocusError> {
-        let data = self.command_response_string("colormap.map")?;
-        string_to_numerical_vec(&data)
-    }
+        /// Sets the color map.
+        ///
+        /// This command writes the color map that assigns each color listed in the palette to individual LEDs, mapping them to the keyboard's current layout.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#colormapmap
+        pub fn color_map_set(&mut self, data: &[u8]) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::color_map(data), true))
+        }
 
-    /// Sets the color map.
-    ///
-    /// This command writes the color map that assigns each color listed in the palette to individual LEDs, mapping them to the keyboard's current layout.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#colormapmap
-    pub fn color_map_set(&mut self, data: &[u8]) -> Result<(), FocusError> {
-        self.command_new_line(
-            &format!("colormap.map {}", numerical_vec_to_string(data)),
-            true,
-        )
-    }
+        /// Gets the idle LED true sleep state.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstrue_sleep
+        pub fn led_idle_true_sleep_get(&mut self) -> Result<bool, FocusError> {
+            io!(self.command_response_bool("idleleds.true_sleep"))
+        }
 
-    /// Gets the idle LED true sleep state.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstrue_sleep
-    pub fn led_idle_true_sleep_get(&mut self) -> Result<bool, FocusError> {
-        self.command_response_bool("idleleds.true_sleep")
-    }
+        /// Sets the idle LED true sleep state.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstrue_sleep
+        pub fn led_idle_true_sleep_set(&mut self, state: bool) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::led_idle_true_sleep(state), true))
+        }
 
-    /// Sets the idle LED true sleep state.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstrue_sleep
-    pub fn led_idle_true_sleep_set(&mut self, state: bool) -> Result<(), FocusError> {
-        self.command_new_line(&format!("idleleds.true_sleep {}", state as u8), true)
-    }
+        /// Gets the idle LED true sleep time in seconds.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstrue_sleep_time
+        pub fn led_idle_true_sleep_time_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("idleleds.true_sleep_time"))
+        }
 
-    /// Gets the idle LED true sleep time in seconds.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstrue_sleep_time
-    pub fn led_idle_true_sleep_time_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("idleleds.true_sleep_time")
-    }
+        /// Sets the idle LED true sleep time in seconds.
+        ///
+        /// Max: 65,000
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstrue_sleep_time
+        pub fn led_idle_true_sleep_time_set(&mut self, seconds: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::led_idle_true_sleep_time(seconds)?, true))
+        }
 
-    /// Sets the idle LED true sleep time in seconds.
-    ///
-    /// Max: 65,000
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstrue_sleep_time
-    pub fn led_idle_true_sleep_time_set(&mut self, seconds: u16) -> Result<(), FocusError> {
-        if seconds > 65_000 {
-            return Err(FocusError::ValueAboveLimitError {
-                label: "seconds",
-                max: 65_000,
-                provided: seconds as usize,
-            });
-        }
-        self.command_new_line(&format!("idleleds.true_sleep_time {}", seconds), true)
-    }
+        /// Gets the idle LED wired time limit in seconds.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstime_limit
+        pub fn led_idle_time_limit_wired_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("idleleds.time_limit"))
+        }
 
-    /// Gets the idle LED wired time limit in seconds.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstime_limit
-    pub fn led_idle_time_limit_wired_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("idleleds.time_limit")
-    }
+        /// Sets the idle LED wired time limit in seconds.
+        ///
+        /// Max: 65,000
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstime_limit
+        pub fn led_idle_time_limit_wired_set(&mut self, seconds: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::led_idle_time_limit_wired(seconds)?, true))
+        }
 
-    /// Sets the idle LED wired time limit in seconds.
-    ///
-    /// Max: 65,000
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledstime_limit
-    pub fn led_idle_time_limit_wired_set(&mut self, seconds: u16) -> Result<(), FocusError> {
-        if seconds > 65_000 {
-            return Err(FocusError::ValueAboveLimitError {
-                label: "seconds",
-                max: 65_000,
-                provided: seconds as usize,
-            });
-        }
-        self.command_new_line(&format!("idleleds.time_limit {}", seconds), true)
-    }
+        /// Gets the idle LED time limit in seconds (wireless).
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledswireless
+        pub fn led_idle_time_limit_wireless_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("idleleds.wireless"))
+        }
 
-    /// Gets the idle LED time limit in seconds (wireless).
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledswireless
-    pub fn led_idle_time_limit_wireless_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("idleleds.wireless")
-    }
+        /// Sets the idle LED time limit in seconds (wireless).
+        ///
+        /// Max: 65,000
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledswireless
+        pub fn led_idle_time_limit_wireless_set(&mut self, seconds: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::led_idle_time_limit_wireless(seconds)?, true))
+        }
 
-    /// Sets the idle LED time limit in seconds (wireless).
-    ///
-    /// Max: 65,000
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#idleledswireless
-    pub fn led_idle_time_limit_wireless_set(&mut self, seconds: u16) -> Result<(), FocusError> {
-        if seconds > 65_000 {
-            return Err(FocusError::ValueAboveLimitError {
-                label: "seconds",
-                max: 65_000,
-                provided: seconds as usize,
-            });
-        }
-        self.command_new_line(&format!("idleleds.wireless {}", seconds), true)
-    }
+        /// Gets the keyboard model name.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwareversion
+        pub fn hardware_version_get(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("hardware.version"))
+        }
 
-    /// Gets the keyboard model name.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwareversion
-    pub fn hardware_version_get(&mut self) -> Result<String, FocusError> {
-        self.command_response_string("hardware.version")
-    }
+        /// Sets the keyboard model name.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwareversion
+        pub fn hardware_version_set(&mut self, data: &str) -> Result<(), FocusError> {
+            io!(self.command_new_line(&format!("hardware.version {}", data), true))
+        }
 
-    /// Sets the keyboard model name.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwareversion
-    pub fn hardware_version_set(&mut self, data: &str) -> Result<(), FocusError> {
-        self.command_new_line(&format!("hardware.version {}", data), true)
-    }
+        /// Gets whether the sides are powered.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwareside_power
+        pub fn hardware_side_power_get(&mut self) -> Result<bool, FocusError> {
+            io!(self.command_response_bool("hardware.side_power"))
+        }
 
-    // TODO: hardware.side_power https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwareside_power
-    // TODO: hardware.side_ver https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwareside_ver
-    // TODO: hardware.keyscanInterval
-    // TODO: hardware.firmware https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwarefirmware
-    // TODO: hardware.chip_id
-    // TODO: hardware.chip_info
+        /// Sets whether the sides are powered.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwareside_power
+        pub fn hardware_side_power_set(&mut self, state: bool) -> Result<(), FocusError> {
+            io!(self.command_new_line(&format!("hardware.side_power {}", state as u8), true))
+        }
 
-    /// Gets the Qukeys hold timeout in milliseconds.
-    ///
-    /// https://kaleidoscope.readthedocs.io/en/latest/plugins/Kaleidoscope-Qukeys.html
-    pub fn qukeys_hold_timeout_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("qukeys.holdTimeout")
-    }
+        /// Gets the keyscanner firmware version of each side.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwareside_ver
+        pub fn hardware_side_version_get(&mut self) -> Result<Sides<u8>, FocusError> {
+            io!(self.query("hardware.side_ver"))
+        }
 
-    /// Sets the Qukeys hold timeout in milliseconds.
-    ///
-    /// https://kaleidoscope.readthedocs.io/en/latest/plugins/Kaleidoscope-Qukeys.html
-    pub fn qukeys_hold_timeout_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
-        self.command_new_line(&format!("qukeys.holdTimeout {}", &milliseconds), true)
-    }
+        /// Gets the LED driver version of each side.
+        pub fn hardware_sled_version_get(&mut self) -> Result<Sides<u8>, FocusError> {
+            io!(self.query("hardware.sled_ver"))
+        }
 
-    /// Gets the Qukeys overlap threshold in milliseconds.
-    ///
-    /// https://kaleidoscope.readthedocs.io/en/latest/plugins/Kaleidoscope-Qukeys.html
-    pub fn qukeys_overlap_threshold_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("qukeys.overlapThreshold")
-    }
+        /// Gets the LED driver current of each side.
+        pub fn hardware_sled_current_get(&mut self) -> Result<Sides<u8>, FocusError> {
+            io!(self.query("hardware.sled_current"))
+        }
 
-    /// Sets the Qukeys overlap threshold in milliseconds.
-    ///
-    /// https://kaleidoscope.readthedocs.io/en/latest/plugins/Kaleidoscope-Qukeys.html
-    pub fn qukeys_overlap_threshold_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
-        self.command_new_line(&format!("qukeys.overlapThreshold {}", &milliseconds), true)
-    }
+        /// Sets the LED driver current of both sides.
+        pub fn hardware_sled_current_set(&mut self, current: u8) -> Result<(), FocusError> {
+            io!(self.command_new_line(&format!("hardware.sled_current {}", current), true))
+        }
 
-    /// Gets the macros map.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#macrosmap
-    pub fn macros_map_get(&mut self) -> Result<Vec<u8>, FocusError> {
-        let data = self.command_response_string("macros.map")?;
-        string_to_numerical_vec(&data)
-    }
+        /// Gets the physical layout, `ANSI` or `ISO`.
+        pub fn hardware_layout_get(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("hardware.layout"))
+        }
 
-    /// Sets the macros map.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#macrosmap
-    pub fn macros_map_set(&mut self, data: &[u8]) -> Result<(), FocusError> {
-        self.command_new_line(
-            &format!("macros.map {}", numerical_vec_to_string(data)),
-            true,
-        )
-    }
+        /// Gets the state of the joint between the sides.
+        pub fn hardware_joint_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("hardware.joint"))
+        }
 
-    /// Triggers a macro.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#macrostrigger
-    pub fn macros_trigger(&mut self, macro_id: u8) -> Result<(), FocusError> {
-        self.command_new_line(&format!("macros.trigger {}", macro_id), true)
-    }
+        /// Gets the keyscan interval in milliseconds.
+        ///
+        /// The command is `hardware.keyscan`, the one the `help` of the Raise and Defy firmware lists.
+        /// None of them lists a `hardware.keyscanInterval` command.
+        pub fn hardware_keyscan_interval_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("hardware.keyscan"))
+        }
 
-    /// Gets the macros memory size in bytes.
-    pub fn macros_memory(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("macros.memory")
-    }
+        /// Sets the keyscan interval in milliseconds.
+        pub fn hardware_keyscan_interval_set(
+            &mut self,
+            milliseconds: u8,
+        ) -> Result<(), FocusError> {
+            io!(self.command_new_line(&format!("hardware.keyscan {}", milliseconds), true))
+        }
 
-    /// Gets all the available commands in the current version of the serial protocol.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#help
-    pub fn help(&mut self) -> Result<Vec<String>, FocusError> {
-        self.command_response_vec_string("help")
-    }
+        /// Gets the number of CRC errors on the link to each side.
+        pub fn hardware_crc_errors_get(&mut self) -> Result<Sides<u32>, FocusError> {
+            io!(self.query("hardware.crc_errors"))
+        }
 
-    /// Gets the virtual mouse speed.
-    pub fn mouse_speed_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("mouse.speed")
-    }
+        /// Gets the firmware build of the Neuron.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#hardwarefirmware
+        pub fn hardware_firmware_get(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("hardware.firmware"))
+        }
 
-    /// Sets the virtual mouse speed.
-    ///
-    /// Max: 127
-    pub fn mouse_speed_set(&mut self, speed: u8) -> Result<(), FocusError> {
-        if speed > 127 {
-            return Err(FocusError::ValueAboveLimitError {
-                label: "speed",
-                max: 127,
-                provided: speed as usize,
-            });
-        }
-        self.command_new_line(&format!("mouse.speed {}", speed), true)
-    }
+        /// Gets the unique chip ID of the Neuron.
+        pub fn hardware_chip_id_get(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("hardware.chip_id"))
+        }
 
-    /// Gets the virtual mouse delay in milliseconds.
-    pub fn mouse_delay_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("mouse.speedDelay")
-    }
+        /// Gets the chip information of the Neuron.
+        pub fn hardware_chip_info_get(&mut self) -> Result<String, FocusError> {
+            io!(self.command_response_string("hardware.chip_info"))
+        }
 
-    /// Sets the virtual mouse delay in milliseconds.
-    pub fn mouse_delay_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
-        self.command_new_line(&format!("mouse.speedDelay {}", &milliseconds), true)
-    }
+        /// Gets every `hardware.*` value in one go.
+        ///
+        /// Values the device answers with an empty or unparsable response are `None`.
+        /// Errors talking to the device are returned, so a lost connection is not mistaken for an unsupported command.
+        pub fn hardware_diagnostics(&mut self) -> Result<HardwareDiagnostics, FocusError> {
+            let non_empty = |value: Option<String>| value.filter(|s| !s.is_empty());
+
+            Ok(HardwareDiagnostics {
+                version: io!(self.hardware_version_get())?,
+                side_power: reported(io!(self.hardware_side_power_get()))?,
+                side_version: reported(io!(self.hardware_side_version_get()))?,
+                sled_version: reported(io!(self.hardware_sled_version_get()))?,
+                sled_current: reported(io!(self.hardware_sled_current_get()))?,
+                layout: non_empty(reported(io!(self.hardware_layout_get()))?),
+                joint: reported(io!(self.hardware_joint_get()))?,
+                keyscan_interval: reported(io!(self.hardware_keyscan_interval_get()))?,
+                crc_errors: reported(io!(self.hardware_crc_errors_get()))?,
+                firmware: non_empty(reported(io!(self.hardware_firmware_get()))?),
+                chip_id: non_empty(reported(io!(self.hardware_chip_id_get()))?),
+                chip_info: non_empty(reported(io!(self.hardware_chip_info_get()))?),
+            })
+        }
 
-    /// Gets the virtual mouse acceleration speed.
-    pub fn mouse_acceleration_speed_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("mouse.accelSpeed")
-    }
+        /// Gets the Qukeys hold timeout in milliseconds.
+        ///
+        /// https://kaleidoscope.readthedocs.io/en/latest/plugins/Kaleidoscope-Qukeys.html
+        pub fn qukeys_hold_timeout_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("qukeys.holdTimeout"))
+        }
 
-    /// Sets the virtual mouse acceleration speed.
-    pub fn mouse_acceleration_speed_set(&mut self, speed: u8) -> Result<(), FocusError> {
-        self.command_new_line(&format!("mouse.accelSpeed {}", speed), true)
-    }
+        /// Sets the Qukeys hold timeout in milliseconds.
+        ///
+        /// https://kaleidoscope.readthedocs.io/en/latest/plugins/Kaleidoscope-Qukeys.html
+        pub fn qukeys_hold_timeout_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::qukeys_hold_timeout(milliseconds), true))
+        }
 
-    /// Gets the virtual mouse acceleration delay in milliseconds.
-    pub fn mouse_acceleration_delay_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("mouse.accelDelay")
-    }
+        /// Gets the Qukeys overlap threshold in milliseconds.
+        ///
+        /// https://kaleidoscope.readthedocs.io/en/latest/plugins/Kaleidoscope-Qukeys.html
+        pub fn qukeys_overlap_threshold_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("qukeys.overlapThreshold"))
+        }
 
-    /// Sets the virtual mouse acceleration delay in milliseconds.
-    pub fn mouse_acceleration_delay_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
-        self.command_new_line(&format!("mouse.accelDelay {}", &milliseconds), true)
-    }
+        /// Sets the Qukeys overlap threshold in milliseconds.
+        ///
+        /// https://kaleidoscope.readthedocs.io/en/latest/plugins/Kaleidoscope-Qukeys.html
+        pub fn qukeys_overlap_threshold_set(
+            &mut self,
+            milliseconds: u16,
+        ) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::qukeys_overlap_threshold(milliseconds), true))
+        }
 
-    /// Gets the virtual mouse wheel speed.
-    pub fn mouse_wheel_speed_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("mouse.wheelSpeed")
-    }
+        /// Gets the macros map.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#macrosmap
+        pub fn macros_map_get(&mut self) -> Result<Vec<u8>, FocusError> {
+            io!(self.command_response_numerical_vec("macros.map"))
+        }
 
-    /// Sets the virtual mouse wheel speed.
-    pub fn mouse_wheel_speed_set(&mut self, speed: u8) -> Result<(), FocusError> {
-        self.command_new_line(&format!("mouse.wheelSpeed {}", speed), true)
-    }
+        /// Sets the macros map.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#macrosmap
+        pub fn macros_map_set(&mut self, data: &[u8]) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::macros_map(data), true))
+        }
 
-    /// Gets the virtual mouse wheel delay in milliseconds.
-    pub fn mouse_wheel_delay_get(&mut self) -> Result<u16, FocusError> {
-        self.command_response_numerical("mouse.wheelDelay")
-    }
+        /// Triggers a macro.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#macrostrigger
+        pub fn macros_trigger(&mut self, macro_id: u8) -> Result<(), FocusError> {
+            io!(self.command_new_line(&format!("macros.trigger {}", macro_id), true))
+        }
 
-    /// Sets the virtual mouse wheel delay in milliseconds.
-    pub fn mouse_wheel_delay_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
-        self.command_new_line(&format!("mouse.wheelDelay {}", &milliseconds), true)
-    }
+        /// Gets the macros memory size in bytes.
+        pub fn macros_memory(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("macros.memory"))
+        }
 
-    /// Gets the virtual mouse speed limit.
-    pub fn mouse_speed_limit_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("mouse.speedLimit")
-    }
+        /// Gets all the available commands in the current version of the serial protocol.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#help
+        pub fn help(&mut self) -> Result<Vec<String>, FocusError> {
+            io!(self.command_response_vec_string("help"))
+        }
 
-    /// Sets the virtual mouse speed limit.
-    pub fn mouse_speed_limit_set(&mut self, limit: u8) -> Result<(), FocusError> {
-        self.command_new_line(&format!("mouse.speedLimit {}", limit), true)
-    }
+        /// Gets the virtual mouse speed.
+        pub fn mouse_speed_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("mouse.speed"))
+        }
 
-    /// Activate a certain layer remotely just by sending its order number.
-    ///
-    /// The layer is -1 to Bazecor.
-    ///
-    /// This does not affect the memory usage as the value is stored in RAM.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#layeractivate
-    pub fn layer_activate(&mut self, layer: u8) -> Result<(), FocusError> {
-        self.command_new_line(&format!("layer.activate {}", layer), true)
-    }
+        /// Sets the virtual mouse speed.
+        ///
+        /// Max: 127
+        pub fn mouse_speed_set(&mut self, speed: u8) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::mouse_speed(speed)?, true))
+        }
 
-    /// Deactivate the last layer that the keyboard switched to.
-    /// This same function is the way the shift to layer key works on the keyboard.
-    ///
-    /// Just provide the layer number to make the keyboard go back one layer. The layer is -1 to Bazecor.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#layerdeactivate
-    pub fn layer_deactivate(&mut self, layer: Option<u8>) -> Result<(), FocusError> {
-        if let Some(layer) = layer {
+        /// Gets the virtual mouse delay in milliseconds.
+        pub fn mouse_delay_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("mouse.speedDelay"))
+        }
+
+        /// Sets the virtual mouse delay in milliseconds.
+        pub fn mouse_delay_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::mouse_delay(milliseconds), true))
+        }
+
+        /// Gets the virtual mouse acceleration speed.
+        pub fn mouse_acceleration_speed_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("mouse.accelSpeed"))
+        }
+
+        /// Sets the virtual mouse acceleration speed.
+        pub fn mouse_acceleration_speed_set(&mut self, speed: u8) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::mouse_acceleration_speed(speed), true))
+        }
+
+        /// Gets the virtual mouse acceleration delay in milliseconds.
+        pub fn mouse_acceleration_delay_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("mouse.accelDelay"))
+        }
+
+        /// Sets the virtual mouse acceleration delay in milliseconds.
+        pub fn mouse_acceleration_delay_set(
+            &mut self,
+            milliseconds: u16,
+        ) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::mouse_acceleration_delay(milliseconds), true))
+        }
+
+        /// Gets the virtual mouse wheel speed.
+        pub fn mouse_wheel_speed_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("mouse.wheelSpeed"))
+        }
+
+        /// Sets the virtual mouse wheel speed.
+        pub fn mouse_wheel_speed_set(&mut self, speed: u8) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::mouse_wheel_speed(speed), true))
+        }
+
+        /// Gets the virtual mouse wheel delay in milliseconds.
+        pub fn mouse_wheel_delay_get(&mut self) -> Result<u16, FocusError> {
+            io!(self.command_response_numerical("mouse.wheelDelay"))
+        }
+
+        /// Sets the virtual mouse wheel delay in milliseconds.
+        pub fn mouse_wheel_delay_set(&mut self, milliseconds: u16) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::mouse_wheel_delay(milliseconds), true))
+        }
+
+        /// Gets the virtual mouse speed limit.
+        pub fn mouse_speed_limit_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("mouse.speedLimit"))
+        }
+
+        /// Sets the virtual mouse speed limit.
+        pub fn mouse_speed_limit_set(&mut self, limit: u8) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::mouse_speed_limit(limit), true))
+        }
+
+        /// Activate a certain layer remotely just by sending its order number.
+        ///
+        /// The layer is -1 to Bazecor.
+        ///
+        /// This does not affect the memory usage as the value is stored in RAM.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#layeractivate
+        pub fn layer_activate(&mut self, layer: u8) -> Result<(), FocusError> {
+            io!(self.command_new_line(&format!("layer.activate {}", layer), true))
+        }
+
+        /// Deactivate the last layer that the keyboard switched to.
+        /// This same function is the way the shift to layer key works on the keyboard.
+        ///
+        /// Just provide the layer number to make the keyboard go back one layer. The layer is -1 to Bazecor.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#layerdeactivate
+        pub fn layer_deactivate(&mut self, layer: Option<u8>) -> Result<(), FocusError> {
+            if let Some(layer) = layer {
+                if layer > MAX_LAYERS {
+                    return Err(FocusError::ValueAboveLimitError {
+                        label: "layer",
+                        max: MAX_LAYERS as usize,
+                        provided: layer as usize,
+                    });
+                }
+                io!(self.command_new_line(&format!("layer.deactivate {}", layer), true))?
+            }
+            io!(self.command_new_line("layer.deactivate", true))
+        }
+
+        /// Gets the state of the provided layer.
+        ///
+        /// The layer is -1 to Bazecor.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#layerisactive
+        pub fn layer_is_active(&mut self, layer: u8) -> Result<bool, FocusError> {
             if layer > MAX_LAYERS {
                 return Err(FocusError::ValueAboveLimitError {
                     label: "layer",
@@ -1065,142 +1243,122 @@ impl Focus {
                     provided: layer as usize,
                 });
             }
-            self.command_new_line(&format!("layer.deactivate {}", layer), true)?
+            io!(self.command_response_bool(&format!("layer.isActive {}", layer)))
         }
-        self.command_new_line("layer.deactivate", true)
-    }
 
-    /// Gets the state of the provided layer.
-    ///
-    /// The layer is -1 to Bazecor.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#layerisactive
-    pub fn layer_is_active(&mut self, layer: u8) -> Result<bool, FocusError> {
-        if layer > MAX_LAYERS {
-            return Err(FocusError::ValueAboveLimitError {
-                label: "layer",
-                max: MAX_LAYERS as usize,
-                provided: layer as usize,
-            });
-        }
-        self.command_response_bool(&format!("layer.isActive {}", layer))
-    }
-
-    /// Switch to a certain layer.
-    ///
-    /// The layer is -1 to Bazecor.
-    ///
-    /// The difference between this command and the layer_activate alternative, is that the layer_activate adds to the layer switching history, but moveTo will erase that memory and return it to an array length 1 and holding the current layer the keyboard moved to.
-    ///
-    /// This does not affect the memory usage as the value is stored in RAM.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#layermoveto
-    pub fn layer_move_to(&mut self, layer: u8) -> Result<(), FocusError> {
-        self.command_new_line(&format!("layer.moveTo {}", layer), true)
-    }
+        /// Switch to a certain layer.
+        ///
+        /// The layer is -1 to Bazecor.
+        ///
+        /// The difference between this command and the layer_activate alternative, is that the layer_activate adds to the layer switching history, but moveTo will erase that memory and return it to an array length 1 and holding the current layer the keyboard moved to.
+        ///
+        /// This does not affect the memory usage as the value is stored in RAM.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#layermoveto
+        pub fn layer_move_to(&mut self, layer: u8) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::layer_move_to(layer)?, true))
+        }
 
-    /// Gets the status for up to 32 layers.
-    ///
-    /// It will return a vector of bools with the respective index matching each layer, -1 from Bazecor.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#layerstate
-    pub fn layer_state(&mut self) -> Result<Vec<bool>, FocusError> {
-        let response = self.command_response_string("layer.state")?;
-        let parts = response.split_whitespace().collect::<Vec<&str>>();
-        let nums = parts.iter().map(|&part| part == "1").collect();
-        Ok(nums)
-    }
+        /// Gets the status for up to 32 layers.
+        ///
+        /// It will return a vector of bools with the respective index matching each layer, -1 from Bazecor.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#layerstate
+        pub fn layer_state(&mut self) -> Result<Vec<bool>, FocusError> {
+            let response = io!(self.command_response_string("layer.state"))?;
+            let parts = response.split_whitespace().collect::<Vec<&str>>();
+            let nums = parts.iter().map(|&part| part == "1").collect();
+            Ok(nums)
+        }
 
-    /// Gets the battery level of the left keyboard as a percentage.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatteryleftlevel
-    pub fn wireless_battery_level_left_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("wireless.battery.left.level")
-    }
+        /// Gets the battery level of the left keyboard as a percentage.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatteryleftlevel
+        pub fn wireless_battery_level_left_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("wireless.battery.left.level"))
+        }
 
-    /// Gets the battery level of the right keyboard as a percentage.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatteryrightlevel
-    pub fn wireless_battery_level_right_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("wireless.battery.right.level")
-    }
+        /// Gets the battery level of the right keyboard as a percentage.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatteryrightlevel
+        pub fn wireless_battery_level_right_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("wireless.battery.right.level"))
+        }
 
-    /// Gets the battery status of the left keyboard.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatteryleftstatus
-    pub fn wireless_battery_status_left_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("wireless.battery.left.status")
-    }
+        /// Gets the battery status of the left keyboard.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatteryleftstatus
+        pub fn wireless_battery_status_left_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("wireless.battery.left.status"))
+        }
 
-    /// Gets the battery status of the right keyboard.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatteryrightstatus
-    pub fn wireless_battery_status_right_get(&mut self) -> Result<u8, FocusError> {
-        self.command_response_numerical("wireless.battery.right.status")
-    }
+        /// Gets the battery status of the right keyboard.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatteryrightstatus
+        pub fn wireless_battery_status_right_get(&mut self) -> Result<u8, FocusError> {
+            io!(self.command_response_numerical("wireless.battery.right.status"))
+        }
 
-    /// Gets the battery saving mode state.
-    ///
-    /// This will be automatically enabled when remaining battery charge is low, but it can be manually enabled earlier.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatterysavingmode
-    pub fn wireless_battery_saving_mode_get(&mut self) -> Result<bool, FocusError> {
-        self.command_response_bool("wireless.battery.savingMode")
-    }
+        /// Gets the battery saving mode state.
+        ///
+        /// This will be automatically enabled when remaining battery charge is low, but it can be manually enabled earlier.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatterysavingmode
+        pub fn wireless_battery_saving_mode_get(&mut self) -> Result<bool, FocusError> {
+            io!(self.command_response_bool("wireless.battery.savingMode"))
+        }
 
-    /// Sets the battery saving mode state.
-    ///
-    /// This will be automatically enabled when remaining battery charge is low, but it can be manually enabled earlier.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatterysavingmode
-    pub fn wireless_battery_saving_mode_set(&mut self, state: bool) -> Result<(), FocusError> {
-        self.command_new_line(
-            &format!("wireless.battery.savingMode {}", state as u8),
-            true,
-        )
-    }
+        /// Sets the battery saving mode state.
+        ///
+        /// This will be automatically enabled when remaining battery charge is low, but it can be manually enabled earlier.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatterysavingmode
+        pub fn wireless_battery_saving_mode_set(&mut self, state: bool) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::wireless_battery_saving_mode(state), true))
+        }
 
-    /// Forces the neuron to update the battery level.
-    ///
-    /// This typically takes a second or two to update the values for the wireless_battery_level commands to read.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatteryforceread
-    pub fn wireless_battery_force_read(&mut self) -> Result<(), FocusError> {
-        self.command_new_line("wireless.battery.forceRead", false)
-    }
+        /// Forces the neuron to update the battery level.
+        ///
+        /// This typically takes a second or two to update the values for the wireless_battery_level commands to read.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessbatteryforceread
+        pub fn wireless_battery_force_read(&mut self) -> Result<(), FocusError> {
+            io!(self.command_new_line("wireless.battery.forceRead", false))
+        }
 
-    /// Gets the RF power level.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessrfpower
-    pub fn wireless_rf_power_level_get(&mut self) -> Result<WirelessPowerMode, FocusError> {
-        self.command_response_numerical("wireless.rf.power")
-    }
+        /// Gets the RF power level.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessrfpower
+        pub fn wireless_rf_power_level_get(&mut self) -> Result<WirelessPowerMode, FocusError> {
+            io!(self.command_response_numerical("wireless.rf.power"))
+        }
 
-    /// Sets the RF power level.
-    pub fn wireless_rf_power_level_set(
-        &mut self,
-        wireless_power_mode: WirelessPowerMode,
-    ) -> Result<(), FocusError> {
-        self.command_new_line(
-            &format!("wireless.rf.power {}", wireless_power_mode as u8),
-            true,
-        )
-    }
+        /// Sets the RF power level.
+        pub fn wireless_rf_power_level_set(
+            &mut self,
+            wireless_power_mode: WirelessPowerMode,
+        ) -> Result<(), FocusError> {
+            io!(self.command_new_line(
+                &commands::wireless_rf_power_level(wireless_power_mode),
+                true
+            ))
+        }
 
-    /// Gets the RF channel hop state.
-    pub fn wireless_rf_channel_hop_get(&mut self) -> Result<bool, FocusError> {
-        self.command_response_bool("wireless.rf.channelHop")
-    }
+        /// Gets the RF channel hop state.
+        pub fn wireless_rf_channel_hop_get(&mut self) -> Result<bool, FocusError> {
+            io!(self.command_response_bool("wireless.rf.channelHop"))
+        }
 
-    /// Sets the RF channel hop state.
-    pub fn wireless_rf_channel_hop_set(&mut self, state: bool) -> Result<(), FocusError> {
-        self.command_new_line(&format!("wireless.rf.channelHop {}", state as u8), true)
-    }
+        /// Sets the RF channel hop state.
+        pub fn wireless_rf_channel_hop_set(&mut self, state: bool) -> Result<(), FocusError> {
+            io!(self.command_new_line(&commands::wireless_rf_channel_hop(state), true))
+        }
 
-    /// Gets the sync pairing state.
-    ///
-    /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessrfsyncpairing
-    pub fn wireless_rf_sync_pairing(&mut self) -> Result<bool, FocusError> {
-        self.command_response_bool("wireless.rf.syncPairing")
+        /// Gets the sync pairing state.
+        ///
+        /// https://github.com/Dygmalab/Bazecor/blob/development/FOCUS_API.md#wirelessrfsyncpairing
+        pub fn wireless_rf_sync_pairing(&mut self) -> Result<bool, FocusError> {
+            io!(self.command_response_bool("wireless.rf.syncPairing"))
+        }
     }
 }
@@ -0,0 +1,197 @@
+use crate::builder::FocusBuilder;
+use crate::errors::FocusError;
+use crate::framer::Framer;
+use crate::hardware::{Device, Hardware};
+use crate::transport::simulator::VirtualFocus;
+use crate::{DEFAULT_BUFFER_CAPACITY, DEFAULT_READ_TIMEOUT};
+use log::trace;
+use std::fmt::Debug;
+use std::io::ErrorKind;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// A byte stream the async Focus API can talk to a device over.
+///
+/// Implemented for anything that is `AsyncRead + AsyncWrite`, such as `tokio_serial::SerialStream` or a `TcpStream`.
+pub trait AsyncTransport: AsyncRead + AsyncWrite + Send + Unpin + Debug {}
+
+impl<T: AsyncRead + AsyncWrite + Send + Unpin + Debug> AsyncTransport for T {}
+
+/// The Dygma Focus API, with every call returning a future that runs on tokio.
+///
+/// Dropping a pending future cancels it, so there is no cancel handle.
+#[derive(Debug)]
+pub struct AsyncFocus {
+    pub(crate) transport: Box<dyn AsyncTransport>,
+    pub(crate) framer: Framer,
+    pub(crate) command: String,
+    pub(crate) command_timeout: Option<Duration>,
+    pub(crate) read_timeout: Duration,
+}
+
+/// Constructors
+impl AsyncFocus {
+    /// Creates a new instance of the async Focus API, connecting to the device via the named serial port.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn new_via_port(port: &str) -> Result<Self, FocusError> {
+        FocusBuilder::new().port(port).build_async()
+    }
+
+    /// Creates a new instance of the async Focus API, talking to the device over the provided transport.
+    pub fn new_via_transport(transport: impl AsyncTransport + 'static) -> Self {
+        Self {
+            transport: Box::new(transport),
+            framer: Framer::with_capacity(DEFAULT_BUFFER_CAPACITY),
+            command: String::new(),
+            command_timeout: None,
+            read_timeout: DEFAULT_READ_TIMEOUT,
+        }
+    }
+
+    /// Creates a new instance of the async Focus API, connected to a simulated keyboard built from the virtual hardware definition.
+    pub fn new_virtual(hardware: &Hardware) -> Result<Self, FocusError> {
+        Ok(Self::new_via_transport(VirtualFocus::new(hardware)?))
+    }
+
+    /// Creates a new instance of the async Focus API, connecting to the device via a reference to the device struct.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn new_via_device(device: &Device) -> Result<Self, FocusError> {
+        FocusBuilder::new().device(device).build_async()
+    }
+
+    /// Creates a new instance of the async Focus API, connecting to the device via first available device.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn new_first_available() -> Result<Self, FocusError> {
+        FocusBuilder::new().build_async()
+    }
+}
+
+/// Transport methods
+impl AsyncFocus {
+    /// Writes bytes to the transport.
+    pub async fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), FocusError> {
+        trace!("Writing bytes: {:02X?}", bytes);
+        self.transport
+            .write_all(bytes)
+            .await
+            .map_err(FocusError::SerialPortWriteError)?;
+        self.transport
+            .flush()
+            .await
+            .map_err(FocusError::SerialPortFlushError)?;
+        Ok(())
+    }
+
+    /// Response from the transport
+    ///
+    /// Waits until the response terminator arrives or the command timeout passes.
+    /// Without a command timeout, the read fails once the transport has been silent for the read timeout.
+    ///
+    /// Bytes after the terminator are kept for the next call, as they belong to the next pipelined response.
+    pub async fn read_string(&mut self) -> Result<String, FocusError> {
+        self.read_response().await.map(str::to_string)
+    }
+
+    /// Response from the transport, borrowed from the response buffer until the next read.
+    ///
+    /// See `read_string` for how long it waits.
+    pub async fn read_response(&mut self) -> Result<&str, FocusError> {
+        let frame = self.read_frame().await?;
+        std::str::from_utf8(frame).map_err(FocusError::Utf8ConversionError)
+    }
+
+    /// Reads until the framer holds a complete response, then takes it.
+    pub(crate) async fn read_frame(&mut self) -> Result<&[u8], FocusError> {
+        let started = Instant::now();
+        let mut chunk = [0u8; 1024];
+        while !self.framer.has_frame() {
+            let wait = match self.command_timeout {
+                Some(deadline) => deadline.saturating_sub(started.elapsed()),
+                None => self.read_timeout,
+            };
+            match tokio::time::timeout(wait, self.transport.read(&mut chunk)).await {
+                Err(_) => {
+                    return Err(FocusError::Timeout {
+                        command: self.command.clone(),
+                        elapsed: started.elapsed(),
+                    })
+                }
+                Ok(Ok(0)) => {
+                    return Err(FocusError::SerialPortReadError(
+                        ErrorKind::UnexpectedEof.into(),
+                    ))
+                }
+                Ok(Ok(size)) => {
+                    trace!("Received bytes: {:02X?}", &chunk[..size]);
+                    self.framer.push(&chunk[..size]);
+                }
+                Ok(Err(e)) if e.kind() == ErrorKind::Interrupted => continue,
+                Ok(Err(e)) => return Err(FocusError::SerialPortReadError(e)),
+            }
+        }
+        let frame = self.framer.next_frame().unwrap_or_default();
+        if !frame.is_empty() {
+            trace!("Command RX: {}", String::from_utf8_lossy(frame));
+        } else {
+            trace!("Command RX: [Ack]");
+        }
+        Ok(frame)
+    }
+
+    /// Gets the deadline for the whole response of a command.
+    pub fn command_timeout(&self) -> Option<Duration> {
+        self.command_timeout
+    }
+
+    /// Sets the deadline for the whole response of a command, `None` waits for as long as data keeps arriving.
+    pub fn set_command_timeout(&mut self, timeout: Option<Duration>) {
+        self.command_timeout = timeout;
+    }
+
+    /// Gets how long the transport may be silent while waiting for a response.
+    pub fn read_timeout(&self) -> Duration {
+        self.read_timeout
+    }
+
+    /// Sets how long the transport may be silent while waiting for a response.
+    pub fn set_read_timeout(&mut self, timeout: Duration) {
+        self.read_timeout = timeout;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::LedMode;
+    use crate::hardware::types::hardware_virtual::{DEFY_WIRED_VIRTUAL, DEFY_WIRELESS_VIRTUAL};
+
+    #[tokio::test]
+    async fn test_async_virtual() {
+        let mut focus = AsyncFocus::new_virtual(&DEFY_WIRELESS_VIRTUAL).unwrap();
+        assert_eq!(80 * 10, focus.keymap_custom_get().await.unwrap().len());
+
+        focus.led_mode_set(LedMode::Rainbow).await.unwrap();
+        assert_eq!(LedMode::Rainbow, focus.led_mode_get().await.unwrap());
+
+        focus.layer_move_to(2).await.unwrap();
+        assert!(focus.layer_state().await.unwrap()[2]);
+
+        let settings = focus.settings_get().await.unwrap();
+        assert!(settings.palette_rgbw.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_async_timeout() {
+        let (client, _server) = tokio::io::duplex(64);
+        let mut focus = AsyncFocus::new_via_transport(client);
+        focus.set_read_timeout(Duration::from_millis(10));
+        let err = focus.version().await.unwrap_err();
+        assert!(matches!(err, FocusError::Timeout { command, .. } if command == "version"));
+
+        let mut focus = AsyncFocus::new_virtual(&DEFY_WIRED_VIRTUAL).unwrap();
+        assert!(!focus.version().await.unwrap().is_empty());
+    }
+}
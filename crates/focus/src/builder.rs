@@ -0,0 +1,254 @@
+#[cfg(feature = "async")]
+use crate::asynchronous::AsyncFocus;
+use crate::errors::FocusError;
+use crate::framer::Framer;
+use crate::hardware::{Device, Product};
+use crate::{DEFAULT_BAUD_RATE, DEFAULT_BUFFER_CAPACITY, DEFAULT_READ_TIMEOUT};
+#[cfg(unix)]
+use log::debug;
+use serialport::SerialPortBuilder;
+#[cfg(unix)]
+use std::os::fd::AsRawFd;
+use std::time::Duration;
+#[cfg(feature = "async")]
+use tokio_serial::SerialPortBuilderExt;
+
+#[cfg(unix)]
+use crate::platform::posix::Focus;
+#[cfg(windows)]
+use crate::platform::windows::Focus;
+
+/// Builds a `Focus` connected to a serial port, with control over the connection options.
+///
+/// Without a port selection, the first supported device found is used.
+#[derive(Debug, Clone)]
+pub struct FocusBuilder {
+    selection: Selection,
+    baud_rate: u32,
+    read_timeout: Duration,
+    command_timeout: Option<Duration>,
+    exclusive: bool,
+    data_terminal_ready: Option<bool>,
+    buffer_capacity: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Selection {
+    FirstAvailable,
+    Port(String),
+    SerialNumber(String),
+    Product(Product),
+}
+
+impl Default for FocusBuilder {
+    fn default() -> Self {
+        Self {
+            selection: Selection::FirstAvailable,
+            baud_rate: DEFAULT_BAUD_RATE,
+            read_timeout: DEFAULT_READ_TIMEOUT,
+            command_timeout: None,
+            exclusive: false,
+            data_terminal_ready: Some(true),
+            buffer_capacity: DEFAULT_BUFFER_CAPACITY,
+        }
+    }
+}
+
+impl FocusBuilder {
+    /// Creates a builder with the default connection options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects the serial port by its path, such as `/dev/ttyACM0` or `COM3`.
+    pub fn port(mut self, port: &str) -> Self {
+        self.selection = Selection::Port(port.to_string());
+        self
+    }
+
+    /// Selects the serial port of a detected device.
+    pub fn device(mut self, device: &Device) -> Self {
+        self.selection = Selection::Port(device.serial_port.clone());
+        self
+    }
+
+    /// Selects the first detected device with the provided USB serial number.
+    pub fn serial_number(mut self, serial_number: &str) -> Self {
+        self.selection = Selection::SerialNumber(serial_number.to_string());
+        self
+    }
+
+    /// Selects the first detected device of the provided product that is not in its bootloader.
+    pub fn product(mut self, product: Product) -> Self {
+        self.selection = Selection::Product(product);
+        self
+    }
+
+    /// Sets the baud rate.
+    ///
+    /// Default: 115,200
+    pub fn baud_rate(mut self, baud_rate: u32) -> Self {
+        self.baud_rate = baud_rate;
+        self
+    }
+
+    /// Sets how long a single read from the serial port may block.
+    ///
+    /// Default: 5 seconds
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = timeout;
+        self
+    }
+
+    /// Sets the deadline for the whole response of a command.
+    ///
+    /// Default: none, responses are waited on for as long as data keeps arriving.
+    pub fn command_timeout(mut self, timeout: Duration) -> Self {
+        self.command_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether the serial port is opened for exclusive access.
+    ///
+    /// Serial ports are always exclusive on Windows, so this only applies to unix.
+    ///
+    /// Default: false
+    pub fn exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    /// Sets the DTR line as the port is opened, `None` leaves it untouched.
+    ///
+    /// Untouched means whatever the OS does on open, Linux raises DTR and Windows does not.
+    /// The device only answers once DTR is raised.
+    /// Ports without modem lines, such as pseudo terminals, are left untouched whatever the state.
+    ///
+    /// Default: `Some(true)`
+    pub fn data_terminal_ready(mut self, state: Option<bool>) -> Self {
+        self.data_terminal_ready = state;
+        self
+    }
+
+    /// Sets the initial capacity of the response buffer in bytes.
+    ///
+    /// Default: 8 KiB
+    pub fn buffer_capacity(mut self, capacity: usize) -> Self {
+        self.buffer_capacity = capacity;
+        self
+    }
+
+    /// Opens the selected serial port and creates the Focus API.
+    pub fn build(&self) -> Result<Focus, FocusError> {
+        let port = self.resolve_port()?;
+        #[cfg_attr(not(unix), allow(unused_mut))]
+        let mut serial = self
+            .port_settings(&port)
+            .open_native()
+            .map_err(FocusError::SerialPortOpenError)?;
+        #[cfg(unix)]
+        {
+            serial
+                .set_exclusive(self.exclusive)
+                .map_err(FocusError::SerialPortConfigurationError)?;
+            self.set_data_terminal_ready(&mut serial)?;
+        }
+
+        let mut focus = Focus::new_via_transport(serial);
+        focus.framer = Framer::with_capacity(self.buffer_capacity);
+        focus.command_timeout = self.command_timeout;
+        Ok(focus)
+    }
+
+    /// Opens the selected serial port and creates the async Focus API.
+    ///
+    /// Must be called from within a tokio runtime.
+    #[cfg(feature = "async")]
+    pub fn build_async(&self) -> Result<AsyncFocus, FocusError> {
+        let port = self.resolve_port()?;
+        #[cfg_attr(not(unix), allow(unused_mut))]
+        let mut serial = self
+            .port_settings(&port)
+            .open_native_async()
+            .map_err(FocusError::SerialPortOpenError)?;
+        #[cfg(unix)]
+        {
+            serial
+                .set_exclusive(self.exclusive)
+                .map_err(FocusError::SerialPortConfigurationError)?;
+            self.set_data_terminal_ready(&mut serial)?;
+        }
+
+        let mut focus = AsyncFocus::new_via_transport(serial);
+        focus.framer = Framer::with_capacity(self.buffer_capacity);
+        focus.command_timeout = self.command_timeout;
+        focus.read_timeout = self.read_timeout;
+        Ok(focus)
+    }
+
+    /// The options the serial port is opened with, including the DTR line on Windows.
+    ///
+    /// On unix the DTR line is set once the port is open, see `set_data_terminal_ready`.
+    fn port_settings(&self, port: &str) -> SerialPortBuilder {
+        let settings = serialport::new(port, self.baud_rate)
+            .data_bits(serialport::DataBits::Eight)
+            .flow_control(serialport::FlowControl::None)
+            .parity(serialport::Parity::None)
+            .stop_bits(serialport::StopBits::One)
+            .timeout(self.read_timeout);
+        match self.data_terminal_ready {
+            Some(state) if cfg!(windows) => settings.dtr_on_open(state),
+            _ => settings.preserve_dtr_on_open(),
+        }
+    }
+
+    /// Sets the DTR line of the opened port, skipping ports without modem lines.
+    ///
+    /// Setting DTR on a pseudo terminal fails with `ENOTTY`, which would otherwise refuse the connection.
+    #[cfg(unix)]
+    fn set_data_terminal_ready(
+        &self,
+        serial: &mut (impl serialport::SerialPort + AsRawFd),
+    ) -> Result<(), FocusError> {
+        let Some(state) = self.data_terminal_ready else {
+            return Ok(());
+        };
+        let mut lines: libc::c_int = 0;
+        // SAFETY: `TIOCMGET` writes the modem lines to the int it is given, and the descriptor is kept open by the port.
+        if unsafe { libc::ioctl(serial.as_raw_fd(), libc::TIOCMGET, &mut lines) } != 0 {
+            let e = std::io::Error::last_os_error();
+            if e.raw_os_error() == Some(libc::ENOTTY) {
+                debug!("Leaving DTR untouched, the port has no modem lines");
+                return Ok(());
+            }
+            return Err(FocusError::SerialPortConfigurationError(e.into()));
+        }
+        serial
+            .write_data_terminal_ready(state)
+            .map_err(FocusError::SerialPortConfigurationError)
+    }
+
+    /// Finds the path of the selected serial port.
+    fn resolve_port(&self) -> Result<String, FocusError> {
+        let devices = || Focus::find_all_devices().map(Vec::into_iter);
+        let device = match &self.selection {
+            Selection::Port(port) => return Ok(port.clone()),
+            Selection::FirstAvailable => devices()?
+                .next()
+                .ok_or(FocusError::NoDevicesDetectedError)?,
+            Selection::SerialNumber(serial_number) => devices()?
+                .find(|device| device.serial_number.as_ref() == Some(serial_number))
+                .ok_or_else(|| FocusError::DeviceNotFoundError {
+                    selector: format!("serial number {}", serial_number),
+                })?,
+            Selection::Product(product) => devices()?
+                .find(|device| {
+                    device.hardware.info.product == *product && !device.hardware.bootloader
+                })
+                .ok_or_else(|| FocusError::DeviceNotFoundError {
+                    selector: format!("product {}", product),
+                })?,
+        };
+        Ok(device.serial_port)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::color::*;
+use crate::enums::{LedMode, WirelessPowerMode};
+use crate::errors::FocusError;
+use crate::helpers::*;
+use crate::MAX_LAYERS;
+
+pub(crate) fn keymap_custom(data: &[u16]) -> String {
+    format!("keymap.custom {}", numerical_vec_to_string(data))
+}
+
+pub(crate) fn keymap_default(data: &[u16]) -> String {
+    format!("keymap.default {}", numerical_vec_to_string(data))
+}
+
+pub(crate) fn keymap_only_custom(state: bool) -> String {
+    format!("keymap.onlyCustom {}", state as u8)
+}
+
+pub(crate) fn settings_default_layer(layer: u8) -> Result<String, FocusError> {
+    check_limit("layer", MAX_LAYERS as usize, layer as usize)?;
+    Ok(format!("settings.defaultLayer {}", layer))
+}
+
+pub(crate) fn superkeys_map(data: &[u16]) -> String {
+    format!("superkeys.map {}", numerical_vec_to_string(data))
+}
+
+pub(crate) fn superkeys_wait_for(milliseconds: u16) -> String {
+    format!("superkeys.waitfor {}", milliseconds)
+}
+
+pub(crate) fn superkeys_timeout(milliseconds: u16) -> String {
+    format!("superkeys.timeout {}", milliseconds)
+}
+
+pub(crate) fn superkeys_repeat(milliseconds: u16) -> String {
+    format!("superkeys.repeat {}", milliseconds)
+}
+
+pub(crate) fn superkeys_hold_start(milliseconds: u16) -> String {
+    format!("superkeys.holdstart {}", milliseconds)
+}
+
+pub(crate) fn superkeys_overlap(percentage: u8) -> Result<String, FocusError> {
+    check_limit("percentage", 80, percentage as usize)?;
+    Ok(format!("superkeys.overlap {}", percentage))
+}
+
+pub(crate) fn layer_move_to(layer: u8) -> Result<String, FocusError> {
+    check_limit("layer", MAX_LAYERS as usize, layer as usize)?;
+    Ok(format!("layer.moveTo {}", layer))
+}
+
+pub(crate) fn led_mode(mode: LedMode) -> String {
+    format!("led.mode {}", mode as u8)
+}
+
+pub(crate) fn led_brightness_top(brightness: u8) -> String {
+    format!("led.brightness {}", brightness)
+}
+
+pub(crate) fn led_brightness_underglow_wired(brightness: u8) -> String {
+    format!("led.brightnessUG {}", brightness)
+}
+
+pub(crate) fn led_brightness_keys_wireless(brightness: u8) -> String {
+    format!("led.brightness.wireless {}", brightness)
+}
+
+pub(crate) fn led_brightness_underglow_wireless(brightness: u8) -> String {
+    format!("led.brightnessUG.wireless {}", brightness)
+}
+
+pub(crate) fn led_fade(fade: u16) -> String {
+    format!("led.fade {}", fade)
+}
+
+pub(crate) fn led_theme(data: &[RGB]) -> String {
+    format!("led.theme {}", rgb_vec_to_string(data))
+}
+
+pub(crate) fn palette_rgb(data: &[RGB]) -> String {
+    format!("palette {}", rgb_vec_to_string(data))
+}
+
+pub(crate) fn palette_rgbw(data: &[RGBW]) -> String {
+    format!("palette {}", rgbw_vec_to_string(data))
+}
+
+pub(crate) fn color_map(data: &[u8]) -> String {
+    format!("colormap.map {}", numerical_vec_to_string(data))
+}
+
+pub(crate) fn led_idle_true_sleep(state: bool) -> String {
+    format!("idleleds.true_sleep {}", state as u8)
+}
+
+pub(crate) fn led_idle_true_sleep_time(seconds: u16) -> Result<String, FocusError> {
+    check_limit("seconds", 65_000, seconds as usize)?;
+    Ok(format!("idleleds.true_sleep_time {}", seconds))
+}
+
+pub(crate) fn led_idle_time_limit_wired(seconds: u16) -> Result<String, FocusError> {
+    check_limit("seconds", 65_000, seconds as usize)?;
+    Ok(format!("idleleds.time_limit {}", seconds))
+}
+
+pub(crate) fn led_idle_time_limit_wireless(seconds: u16) -> Result<String, FocusError> {
+    check_limit("seconds", 65_000, seconds as usize)?;
+    Ok(format!("idleleds.wireless {}", seconds))
+}
+
+pub(crate) fn qukeys_hold_timeout(milliseconds: u16) -> String {
+    format!("qukeys.holdTimeout {}", milliseconds)
+}
+
+pub(crate) fn qukeys_overlap_threshold(milliseconds: u16) -> String {
+    format!("qukeys.overlapThreshold {}", milliseconds)
+}
+
+pub(crate) fn macros_map(data: &[u8]) -> String {
+    format!("macros.map {}", numerical_vec_to_string(data))
+}
+
+pub(crate) fn mouse_speed(speed: u8) -> Result<String, FocusError> {
+    check_limit("speed", 127, speed as usize)?;
+    Ok(format!("mouse.speed {}", speed))
+}
+
+pub(crate) fn mouse_delay(milliseconds: u16) -> String {
+    format!("mouse.speedDelay {}", milliseconds)
+}
+
+pub(crate) fn mouse_acceleration_speed(speed: u8) -> String {
+    format!("mouse.accelSpeed {}", speed)
+}
+
+pub(crate) fn mouse_acceleration_delay(milliseconds: u16) -> String {
+    format!("mouse.accelDelay {}", milliseconds)
+}
+
+pub(crate) fn mouse_wheel_speed(speed: u8) -> String {
+    format!("mouse.wheelSpeed {}", speed)
+}
+
+pub(crate) fn mouse_wheel_delay(milliseconds: u16) -> String {
+    format!("mouse.wheelDelay {}", milliseconds)
+}
+
+pub(crate) fn mouse_speed_limit(limit: u8) -> String {
+    format!("mouse.speedLimit {}", limit)
+}
+
+pub(crate) fn wireless_battery_saving_mode(state: bool) -> String {
+    format!("wireless.battery.savingMode {}", state as u8)
+}
+
+pub(crate) fn wireless_rf_power_level(wireless_power_mode: WirelessPowerMode) -> String {
+    format!("wireless.rf.power {}", wireless_power_mode as u8)
+}
+
+pub(crate) fn wireless_rf_channel_hop(state: bool) -> String {
+    format!("wireless.rf.channelHop {}", state as u8)
+}
@@ -0,0 +1,145 @@
+use crate::errors::FocusError;
+use crate::response::FromFocusResponse;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A value reported separately for each side of a split keyboard.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sides<T> {
+    pub left: T,
+    pub right: T,
+}
+
+impl<T: FromStr> FromFocusResponse for Sides<T> {
+    /// Parses responses such as `left: 5\r\nright: 5` or `left: 0 right: 0`.
+    fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+        if response.is_empty() {
+            return Err(FocusError::EmptyResponseError);
+        }
+
+        let mut left = None;
+        let mut right = None;
+        let mut parts = response.split_whitespace();
+
+        while let Some(label) = parts.next() {
+            let value = parts
+                .next()
+                .and_then(|value| value.parse::<T>().ok())
+                .ok_or_else(|| FocusError::ParseNumericalError {
+                    string: response.to_string(),
+                })?;
+
+            match label {
+                "left:" => left = Some(value),
+                "right:" => right = Some(value),
+                _ => {
+                    return Err(FocusError::ParseNumericalError {
+                        string: response.to_string(),
+                    })
+                }
+            }
+        }
+
+        match (left, right) {
+            (Some(left), Some(right)) => Ok(Sides { left, right }),
+            _ => Err(FocusError::PartCountError { expected: 2 }),
+        }
+    }
+}
+
+/// A snapshot of everything the `hardware.*` commands report, for troubleshooting a keyboard.
+///
+/// Everything except the version is optional, as older firmware and wired-only devices do not support every command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_camel_case", serde(rename_all = "camelCase"))]
+pub struct HardwareDiagnostics {
+    pub version: String,
+    pub side_power: Option<bool>,
+    pub side_version: Option<Sides<u8>>,
+    pub sled_version: Option<Sides<u8>>,
+    pub sled_current: Option<Sides<u8>>,
+    pub layout: Option<String>,
+    pub joint: Option<u16>,
+    pub keyscan_interval: Option<u8>,
+    pub crc_errors: Option<Sides<u32>>,
+    pub firmware: Option<String>,
+    pub chip_id: Option<String>,
+    pub chip_info: Option<String>,
+}
+
+/// Turns a value the device does not report, answered with an empty or unparsable response, into `None`.
+///
+/// Any other error, such as a timeout or a lost connection, is kept.
+pub(crate) fn reported<T>(value: Result<T, FocusError>) -> Result<Option<T>, FocusError> {
+    match value {
+        Ok(value) => Ok(Some(value)),
+        Err(
+            FocusError::EmptyResponseError
+            | FocusError::PartCountError { .. }
+            | FocusError::Utf8ConversionError(_)
+            | FocusError::ParseBoolError { .. }
+            | FocusError::ParseIntError(_)
+            | FocusError::ParseNumericalError { .. },
+        ) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_virtual::DEFY_WIRED_VIRTUAL;
+    use crate::prelude::*;
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    #[test]
+    fn test_sides_parse() {
+        let expected = Sides { left: 5, right: 5 };
+        assert_eq!(
+            expected,
+            Sides::<u8>::from_focus_response("left: 5\r\nright: 5").unwrap()
+        );
+        assert_eq!(
+            Sides { left: 0, right: 3 },
+            Sides::<u32>::from_focus_response("left: 0 right: 3").unwrap()
+        );
+        assert!(Sides::<u8>::from_focus_response("left: 5").is_err());
+        assert!(Sides::<u8>::from_focus_response("").is_err());
+    }
+
+    #[test]
+    fn test_hardware_diagnostics_virtual() {
+        let mut focus = Focus::new_virtual(&DEFY_WIRED_VIRTUAL).unwrap();
+        let diagnostics = focus.hardware_diagnostics().unwrap();
+        assert_eq!("Dygma Defy", diagnostics.version);
+        assert_eq!(Some(true), diagnostics.side_power);
+        assert_eq!(Some(Sides { left: 5, right: 5 }), diagnostics.side_version);
+        assert_eq!(Some(Sides { left: 0, right: 0 }), diagnostics.crc_errors);
+        assert_eq!(Some(50), diagnostics.keyscan_interval);
+        assert_eq!(None, diagnostics.chip_info);
+    }
+
+    #[test]
+    fn test_hardware_diagnostics_disconnected() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let peer = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 64];
+            let _ = stream.read(&mut request).unwrap();
+            stream.write_all(b"Dygma Defy\r\n.\r\n").unwrap();
+        });
+
+        let mut focus = Focus::new_via_transport(TcpStream::connect(address).unwrap());
+        let result = focus.hardware_diagnostics();
+        peer.join().unwrap();
+
+        assert!(matches!(result, Err(e) if e.is_connection_error()));
+    }
+}
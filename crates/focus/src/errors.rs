@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -27,6 +28,36 @@ pub enum FocusError {
     #[error("error configuring serial port: {0}")]
     SerialPortConfigurationError(#[source] serialport::Error),
 
+    #[cfg(target_arch = "wasm32")]
+    #[error("web serial error: {0}")]
+    WebSerialError(String),
+
+    #[cfg(target_os = "linux")]
+    #[error("error opening pseudo terminal: {0}")]
+    PtyError(#[source] std::io::Error),
+
+    #[cfg(feature = "fixtures")]
+    #[error("error reading or writing fixture: {0}")]
+    FixtureError(#[source] std::io::Error),
+
+    #[cfg(unix)]
+    #[error("focusd socket error: {0}")]
+    DaemonError(#[source] std::io::Error),
+
+    #[cfg(unix)]
+    #[error("focusd failed to run {command}: {message}")]
+    DaemonCommandError { command: String, message: String },
+
+    #[cfg(feature = "server")]
+    #[error("server error: {0}")]
+    ServerError(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("timed out after {elapsed:?} waiting for the response to: {command}")]
+    Timeout { command: String, elapsed: Duration },
+
+    #[error("cancelled while waiting for the response to: {command}")]
+    Cancelled { command: String },
+
     #[error("response is empty")]
     EmptyResponseError,
 
@@ -55,6 +86,9 @@ pub enum FocusError {
     #[error("failed to parse to numerical vec: {string}")]
     ParseNumericalVecError { string: String },
 
+    #[error("unknown key: {name}")]
+    ParseKeyError { name: String },
+
     #[error("chuck does not contain expected parts (actual: {actual}, expected: {expected}")]
     ChunkCountError { actual: usize, expected: usize },
 
@@ -64,6 +98,47 @@ pub enum FocusError {
     #[error("no devices were detected")]
     NoDevicesDetectedError,
 
+    #[error("no device matching {selector} was detected")]
+    DeviceNotFoundError { selector: String },
+
+    #[error("hardware has no virtual definition: {name}")]
+    NoVirtualInfoError { name: &'static str },
+
+    #[error("hardware has no keyboard grid: {name}")]
+    NoKeyboardGridError { name: &'static str },
+
+    #[error("keymap length {length} is not a multiple of the {layer_size} keys in a layer")]
+    KeymapLengthError { length: usize, layer_size: usize },
+
+    #[error("color map length {length} does not match the keymap layers (expected: {expected})")]
+    ColorMapLengthError { length: usize, expected: usize },
+
+    #[error("{key} cannot refer to layer {layer}")]
+    LayerReferenceError { key: String, layer: u8 },
+
+    #[error("superkey {index} has {actions} actions, at most 5 are supported")]
+    SuperkeyActionsError { index: usize, actions: usize },
+
+    #[error("{action} can't be a superkey action, it would read back as no action or the end of the superkey")]
+    SuperkeyActionError { action: String },
+
     #[error("side disconnected: {side:?}")]
     SideDisconnectedError { side: crate::enums::Side },
 }
+
+impl FocusError {
+    /// Whether the error means the connection to the device was lost or is unresponsive.
+    pub fn is_connection_error(&self) -> bool {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            FocusError::SerialPortOpenError(_)
+            | FocusError::SerialPortReadError(_)
+            | FocusError::SerialPortWriteError(_)
+            | FocusError::SerialPortFlushError(_) => true,
+            #[cfg(target_arch = "wasm32")]
+            FocusError::WebSerialError(_) => true,
+            FocusError::Timeout { .. } => true,
+            _ => false,
+        }
+    }
+}
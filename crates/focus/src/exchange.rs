@@ -0,0 +1,157 @@
+use crate::framer::{whitespace_command, Framer};
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "fixtures")]
+use crate::errors::FocusError;
+#[cfg(feature = "fixtures")]
+use std::fs::{File, OpenOptions};
+#[cfg(feature = "fixtures")]
+use std::io::{BufRead, BufReader, LineWriter, Write};
+#[cfg(feature = "fixtures")]
+use std::path::Path;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A request sent to the device, and the framed response it got back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_camel_case", serde(rename_all = "camelCase"))]
+pub struct Exchange {
+    /// When the request was sent, in milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    /// When the response was framed, in milliseconds since the Unix epoch.
+    ///
+    /// The same as `timestamp` for a command that gets no response, and zero in fixtures that predate it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub response_timestamp: u64,
+    /// The command line, without the trailing new line.
+    pub request: String,
+    /// The response, without the terminator and surrounding whitespace.
+    pub response: String,
+}
+
+impl Exchange {
+    /// Creates an exchange with both timestamps set to the current time.
+    pub fn now(request: impl Into<String>, response: impl Into<String>) -> Self {
+        let now = now_millis();
+        Self {
+            timestamp: now,
+            response_timestamp: now,
+            request: request.into(),
+            response: response.into(),
+        }
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+/// Pairs the bytes going to and coming from a device into exchanges.
+///
+/// A request ends at a new line, at the whitespace after a lone command name that ends a write, or when the
+/// next response is framed. Responses are framed, and matched with the requests in the order they were sent.
+#[derive(Debug, Default)]
+pub(crate) struct ExchangeTracker {
+    line: Vec<u8>,
+    pending: VecDeque<Exchange>,
+    framer: Framer,
+}
+
+impl ExchangeTracker {
+    /// Tracks bytes written to the device, returning the exchange of a command that gets no response.
+    pub(crate) fn sent(&mut self, bytes: &[u8]) -> Option<Exchange> {
+        for &byte in bytes {
+            if byte == b'\n' {
+                self.end_request();
+            } else {
+                self.line.push(byte);
+            }
+        }
+        let name = whitespace_command(&self.line)?;
+        let exchange = Exchange::now(String::from_utf8_lossy(name), "");
+        self.line.clear();
+        Some(exchange)
+    }
+
+    /// Tracks bytes read from the device, returning the exchanges they completed.
+    pub(crate) fn received(&mut self, bytes: &[u8]) -> Vec<Exchange> {
+        self.framer.push(bytes);
+        let mut exchanges = Vec::new();
+        while let Some(frame) = self.framer.next_frame() {
+            let response = String::from_utf8_lossy(frame).into_owned();
+            self.end_request();
+            let mut exchange = self
+                .pending
+                .pop_front()
+                .unwrap_or_else(|| Exchange::now("", ""));
+            exchange.response = response;
+            exchange.response_timestamp = now_millis();
+            exchanges.push(exchange);
+        }
+        exchanges
+    }
+
+    fn end_request(&mut self) {
+        let line = String::from_utf8_lossy(&self.line).trim().to_string();
+        self.line.clear();
+        if !line.is_empty() {
+            self.pending.push_back(Exchange::now(line, ""));
+        }
+    }
+}
+
+/// Reads a fixture file, one JSON encoded exchange per line.
+#[cfg(feature = "fixtures")]
+pub fn read_fixture(path: impl AsRef<Path>) -> Result<Vec<Exchange>, FocusError> {
+    let file = File::open(path).map_err(FocusError::FixtureError)?;
+    BufReader::new(file)
+        .lines()
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|line| {
+            let line = line.map_err(FocusError::FixtureError)?;
+            serde_json::from_str(&line).map_err(|e| FocusError::FixtureError(e.into()))
+        })
+        .collect()
+}
+
+/// Writes exchanges to a fixture file as they happen, one JSON encoded exchange per line.
+#[cfg(feature = "fixtures")]
+#[derive(Debug)]
+pub struct FixtureWriter {
+    file: LineWriter<File>,
+}
+
+#[cfg(feature = "fixtures")]
+impl FixtureWriter {
+    /// Creates the fixture file, replacing an existing one.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, FocusError> {
+        Self::open(
+            path,
+            OpenOptions::new().write(true).create(true).truncate(true),
+        )
+    }
+
+    /// Opens the fixture file for appending, creating it if needed.
+    pub fn append(path: impl AsRef<Path>) -> Result<Self, FocusError> {
+        Self::open(path, OpenOptions::new().append(true).create(true))
+    }
+
+    /// Writes a single exchange.
+    pub fn write(&mut self, exchange: &Exchange) -> Result<(), FocusError> {
+        let line =
+            serde_json::to_string(exchange).map_err(|e| FocusError::FixtureError(e.into()))?;
+        writeln!(self.file, "{}", line).map_err(FocusError::FixtureError)
+    }
+
+    fn open(path: impl AsRef<Path>, options: &OpenOptions) -> Result<Self, FocusError> {
+        let file = options.open(path).map_err(FocusError::FixtureError)?;
+        Ok(Self {
+            file: LineWriter::new(file),
+        })
+    }
+}
@@ -0,0 +1,376 @@
+use crate::errors::FocusError;
+use crate::framer::EOF_MARKER;
+use crate::platform::posix::Focus;
+use crate::resilient::ResilientFocus;
+use crate::transport::Transport;
+use log::{debug, trace, warn};
+use std::fs::{DirBuilder, Permissions};
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Lists the devices the daemon owns, one `<id> <name>` per line.
+pub const DEVICES_COMMAND: &str = "focusd.devices";
+
+/// Selects the device the following commands of the client go to, answers `true` when it exists.
+pub const USE_COMMAND: &str = "focusd.use";
+
+/// Starts the response the daemon sends when a command fails, followed by the reason.
+///
+/// A `Focus` from `Focus::new_via_daemon` turns it into `FocusError::DaemonCommandError`.
+pub const ERROR_PREFIX: &str = "focusd.error ";
+
+/// Gets where the daemon listens by default, in the runtime directory.
+///
+/// Fails when `XDG_RUNTIME_DIR` is not set, as a socket in a shared directory such as `/tmp` could be taken by another user.
+pub fn default_socket_path() -> Result<PathBuf, FocusError> {
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .map(|dir| PathBuf::from(dir).join("focusd.sock"))
+        .ok_or_else(|| {
+            FocusError::DaemonError(std::io::Error::new(
+                ErrorKind::NotFound,
+                "XDG_RUNTIME_DIR is not set, pass the socket path explicitly",
+            ))
+        })
+}
+
+/// A daemon that owns the keyboard connections and shares them with any number of local clients.
+///
+/// Clients speak the Focus protocol over a Unix domain socket, so a `Focus` from `Focus::new_via_daemon` works unchanged.
+/// Commands are run one at a time per device, so clients never see each other's responses.
+/// Each client starts on the first device, and can switch with `focusd.use <id>`.
+#[derive(Debug, Default)]
+pub struct Focusd {
+    devices: Vec<DaemonDevice>,
+}
+
+#[derive(Debug)]
+struct DaemonDevice {
+    id: String,
+    name: String,
+    connection: Mutex<Connection>,
+}
+
+#[derive(Debug)]
+enum Connection {
+    Direct(Box<Focus>),
+    Resilient(Box<ResilientFocus>),
+}
+
+impl Connection {
+    fn query(&mut self, command: &str) -> Result<String, FocusError> {
+        match self {
+            Connection::Direct(focus) => focus.query(command),
+            Connection::Resilient(focus) => focus.run_once(|focus| focus.query(command)),
+        }
+    }
+}
+
+impl Focusd {
+    /// Creates a daemon without devices.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a daemon owning every supported device that is connected, following each across unplugs.
+    pub fn discover() -> Result<Self, FocusError> {
+        let mut daemon = Self::new();
+        for device in Focus::find_all_devices()? {
+            match ResilientFocus::new(&device) {
+                Ok(focus) => {
+                    daemon.devices.push(DaemonDevice {
+                        id: device.serial_port.clone(),
+                        name: device.to_string(),
+                        connection: Mutex::new(Connection::Resilient(Box::new(focus))),
+                    });
+                }
+                Err(e) => warn!("Skipping {} on {}: {}", device, device.serial_port, e),
+            }
+        }
+        Ok(daemon)
+    }
+
+    /// Adds a connection that clients can select by `id`.
+    pub fn add(&mut self, id: impl Into<String>, name: impl Into<String>, focus: Focus) {
+        self.devices.push(DaemonDevice {
+            id: id.into(),
+            name: name.into(),
+            connection: Mutex::new(Connection::Direct(Box::new(focus))),
+        });
+    }
+
+    /// Gets the ids of the owned devices.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.devices.iter().map(|device| device.id.as_str())
+    }
+
+    /// Listens on the socket and serves clients until accepting fails, each client on its own thread.
+    ///
+    /// A stale socket left behind by a daemon that is no longer running is replaced, any other file at the path is kept.
+    /// The socket is only accessible to the user running the daemon.
+    pub fn run(self, path: impl AsRef<Path>) -> Result<(), FocusError> {
+        let path = path.as_ref();
+        let socket =
+            std::fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_socket());
+        if socket && UnixStream::connect(path).is_err() {
+            debug!("Removing stale socket: {}", path.display());
+            std::fs::remove_file(path).map_err(FocusError::DaemonError)?;
+        }
+        let listener = bind_owner_only(path).map_err(FocusError::DaemonError)?;
+        debug!("Listening on {}", path.display());
+
+        let devices = Arc::new(self.devices);
+        for stream in listener.incoming() {
+            let stream = stream.map_err(FocusError::DaemonError)?;
+            let devices = devices.clone();
+            thread::spawn(move || {
+                if let Err(e) = serve_client(stream, &devices) {
+                    debug!("Client disconnected: {}", e);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Binds a listener at the path that only the current user can connect to, leaving the umask of the process alone.
+///
+/// The socket accepts connections as soon as it is bound, so it is bound in a private directory first
+/// and only linked to the path once its mode is set. Linking fails like binding does when the path is taken.
+fn bind_owner_only(path: &Path) -> std::io::Result<UnixListener> {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}", std::process::id()));
+    let dir = path.with_file_name(name);
+    DirBuilder::new().mode(0o700).create(&dir)?;
+    let private = dir.join("socket");
+    let listener = UnixListener::bind(&private).and_then(|listener| {
+        std::fs::set_permissions(&private, Permissions::from_mode(0o600))?;
+        std::fs::hard_link(&private, path)?;
+        Ok(listener)
+    });
+    let _ = std::fs::remove_file(&private);
+    let _ = std::fs::remove_dir(&dir);
+    listener
+}
+
+/// Answers the commands of a single client until it disconnects.
+fn serve_client(stream: UnixStream, devices: &[DaemonDevice]) -> std::io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    let mut selected = 0;
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        let command = line.trim();
+        if command.is_empty() {
+            continue;
+        }
+        trace!("Client RX: {}", command);
+
+        let response = match command.split_once(char::is_whitespace) {
+            _ if command == DEVICES_COMMAND => Ok(devices
+                .iter()
+                .map(|device| format!("{} {}", device.id, device.name))
+                .collect::<Vec<String>>()
+                .join("\r\n")),
+            Some((USE_COMMAND, id)) => {
+                match devices.iter().position(|device| device.id == id.trim()) {
+                    Some(index) => {
+                        selected = index;
+                        Ok("true".to_string())
+                    }
+                    None => Ok("false".to_string()),
+                }
+            }
+            _ => match devices.get(selected) {
+                Some(device) => {
+                    let mut connection = device
+                        .connection
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner());
+                    connection.query(command).map_err(|e| {
+                        warn!("{} failed on {}: {}", command, device.id, e);
+                        e.to_string()
+                    })
+                }
+                None => Err("no device is selected".to_string()),
+            },
+        };
+
+        match response {
+            Ok(response) => writer.write_all(response.as_bytes())?,
+            Err(message) => write!(writer, "{}{}", ERROR_PREFIX, message)?,
+        }
+        writer.write_all(EOF_MARKER)?;
+    }
+    Ok(())
+}
+
+/// The client end of the daemon socket, which tells the error responses of the daemon apart from the device responses.
+#[derive(Debug)]
+struct DaemonStream(UnixStream);
+
+impl Read for DaemonStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl Write for DaemonStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl Transport for DaemonStream {
+    fn timeout(&self) -> Duration {
+        self.0.timeout()
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        self.0.set_timeout(timeout)
+    }
+
+    fn response_error(&self, command: &str, response: &[u8]) -> Option<FocusError> {
+        let message = response.strip_prefix(ERROR_PREFIX.as_bytes())?;
+        Some(FocusError::DaemonCommandError {
+            command: command.to_string(),
+            message: String::from_utf8_lossy(message).into_owned(),
+        })
+    }
+}
+
+/// Daemon specific commands, for a `Focus` connected with `Focus::new_via_daemon`.
+impl Focus {
+    /// Creates a new instance of the Focus API, talking to a device owned by the daemon listening on the socket.
+    pub fn new_via_daemon(path: impl AsRef<Path>) -> Result<Self, FocusError> {
+        let stream = UnixStream::connect(path).map_err(FocusError::DaemonError)?;
+        Ok(Self::new_via_transport(DaemonStream(stream)))
+    }
+
+    /// Lists the devices owned by the daemon, as `(id, name)` pairs.
+    pub fn focusd_devices(&mut self) -> Result<Vec<(String, String)>, FocusError> {
+        let lines: Vec<String> = self.query(DEVICES_COMMAND)?;
+        Ok(lines
+            .into_iter()
+            .map(|line| match line.split_once(' ') {
+                Some((id, name)) => (id.to_string(), name.to_string()),
+                None => (line, String::new()),
+            })
+            .collect())
+    }
+
+    /// Sends the following commands to the device owned by the daemon with the id.
+    pub fn focusd_use(&mut self, id: &str) -> Result<(), FocusError> {
+        if self.query::<bool>(&format!("{} {}", USE_COMMAND, id))? {
+            Ok(())
+        } else {
+            Err(FocusError::DeviceNotFoundError {
+                selector: id.to_string(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_virtual::{DEFY_WIRED_VIRTUAL, RAISE_ANSI_VIRTUAL};
+
+    #[test]
+    fn test_focusd_shares_devices() {
+        let path = std::env::temp_dir().join(format!("focusd_{}.sock", std::process::id()));
+        let mut daemon = Focusd::new();
+        daemon.add(
+            "defy",
+            "Dygma Defy",
+            Focus::new_virtual(&DEFY_WIRED_VIRTUAL).unwrap(),
+        );
+        daemon.add(
+            "raise",
+            "Dygma Raise",
+            Focus::new_virtual(&RAISE_ANSI_VIRTUAL).unwrap(),
+        );
+        thread::spawn({
+            let path = path.clone();
+            move || daemon.run(path)
+        });
+        while UnixStream::connect(&path).is_err() {
+            thread::yield_now();
+        }
+
+        let mut first = Focus::new_via_daemon(&path).unwrap();
+        let mut second = Focus::new_via_daemon(&path).unwrap();
+        second.focusd_use("raise").unwrap();
+        first.led_brightness_top_set(42).unwrap();
+
+        assert_eq!(2, first.focusd_devices().unwrap().len());
+        assert_eq!("Dygma Defy", first.hardware_version_get().unwrap());
+        assert_eq!("Dygma Raise", second.hardware_version_get().unwrap());
+        assert_eq!(
+            42,
+            Focus::new_via_daemon(&path)
+                .unwrap()
+                .led_brightness_top_get()
+                .unwrap()
+        );
+        assert!(second.focusd_use("missing").is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_focusd_reports_errors() {
+        let path = std::env::temp_dir().join(format!("focusd_errors_{}.sock", std::process::id()));
+        thread::spawn({
+            let path = path.clone();
+            move || Focusd::new().run(path)
+        });
+        while UnixStream::connect(&path).is_err() {
+            thread::yield_now();
+        }
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(0o600, mode & 0o777);
+        let mut focus = Focus::new_via_daemon(&path).unwrap();
+        assert!(matches!(
+            focus.version(),
+            Err(FocusError::DaemonCommandError { command, .. }) if command == "version"
+        ));
+        assert!(focus.focusd_devices().unwrap().is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_focusd_keeps_other_files() {
+        let path = std::env::temp_dir().join(format!("focusd_file_{}.sock", std::process::id()));
+        std::fs::write(&path, "not a socket").unwrap();
+
+        assert!(matches!(
+            Focusd::new().run(&path),
+            Err(FocusError::DaemonError(_))
+        ));
+        assert_eq!("not a socket", std::fs::read_to_string(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_device_frames_are_not_daemon_errors() {
+        let (client, mut device) = UnixStream::pair().unwrap();
+        device
+            .write_all(b"focusd.error from the keyboard\r\n.\r\n")
+            .unwrap();
+
+        let mut focus = Focus::new_via_transport(client);
+
+        assert_eq!(
+            "focusd.error from the keyboard",
+            focus.query::<String>("version").unwrap()
+        );
+    }
+}
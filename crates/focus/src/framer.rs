@@ -0,0 +1,159 @@
+use crate::errors::FocusError;
+use std::collections::VecDeque;
+
+/// The terminator that ends every Focus response.
+pub const EOF_MARKER: &[u8] = b"\r\n.\r\n";
+
+/// Returns the command name if `bytes` is a lone command ended by a whitespace other than a new line.
+///
+/// That is how `command_whitespace` sends commands like `upgrade.keyscanner.sendWrite`, which get no response.
+pub(crate) fn whitespace_command(bytes: &[u8]) -> Option<&[u8]> {
+    let (&last, name) = bytes.split_last()?;
+    let lone = !name.is_empty() && !name.iter().any(u8::is_ascii_whitespace);
+    (last != b'\n' && last.is_ascii_whitespace() && lone).then_some(name)
+}
+
+/// Splits the byte stream from the device into responses, one chunk at a time.
+///
+/// The terminator is matched incrementally, so it is found even when it is split across chunks,
+/// and every byte is only looked at once no matter how large the response is.
+/// Bytes after a terminator are kept, as they belong to the next pipelined response.
+#[derive(Debug, Default)]
+pub struct Framer {
+    buffer: Vec<u8>,
+    consumed: usize,
+    matched: usize,
+    ends: VecDeque<usize>,
+}
+
+impl Framer {
+    /// Creates a framer with an empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a framer whose buffer can hold `capacity` bytes before growing.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a chunk read from the device, dropping the NUL bytes the firmware pads with.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.compact();
+        self.buffer.reserve(chunk.len());
+        for &byte in chunk.iter().filter(|&&byte| byte != 0) {
+            self.buffer.push(byte);
+            self.matched = match byte {
+                _ if byte == EOF_MARKER[self.matched] => self.matched + 1,
+                b'\r' => 1,
+                _ => 0,
+            };
+            if self.matched == EOF_MARKER.len() {
+                self.ends.push_back(self.buffer.len());
+                self.matched = 0;
+            }
+        }
+    }
+
+    /// Whether a complete response is waiting to be taken.
+    pub fn has_frame(&self) -> bool {
+        !self.ends.is_empty()
+    }
+
+    /// Takes the next complete response, without the terminator and surrounding whitespace.
+    ///
+    /// The bytes are borrowed from the framer until the next chunk is pushed.
+    pub fn next_frame(&mut self) -> Option<&[u8]> {
+        let end = self.ends.pop_front()?;
+        let start = std::mem::replace(&mut self.consumed, end);
+        Some(self.buffer[start..end - EOF_MARKER.len()].trim_ascii())
+    }
+
+    /// Takes the next complete response as a string, see `next_frame`.
+    pub fn next_response(&mut self) -> Option<Result<&str, FocusError>> {
+        self.next_frame()
+            .map(|frame| std::str::from_utf8(frame).map_err(FocusError::Utf8ConversionError))
+    }
+
+    /// Drops every buffered byte, including partial and unclaimed responses.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.consumed = 0;
+        self.matched = 0;
+        self.ends.clear();
+    }
+
+    /// Drops the responses that were already taken, keeping the rest.
+    fn compact(&mut self) {
+        if self.consumed == 0 {
+            return;
+        }
+        self.buffer.drain(..self.consumed);
+        self.ends.iter_mut().for_each(|end| *end -= self.consumed);
+        self.consumed = 0;
+    }
+}
+
+/// Parses a whitespace separated list of unsigned numbers straight from the response bytes.
+pub fn parse_numerical_vec<T: TryFrom<u64>>(bytes: &[u8]) -> Result<Vec<T>, FocusError> {
+    let invalid = || FocusError::ParseNumericalVecError {
+        string: String::from_utf8_lossy(bytes).into_owned(),
+    };
+    let mut values = Vec::with_capacity(bytes.len() / 2 + 1);
+    let mut value: Option<u64> = None;
+    for &byte in bytes.iter().chain(std::iter::once(&b' ')) {
+        match byte {
+            b'0'..=b'9' => {
+                let digit = (byte - b'0') as u64;
+                value = Some(
+                    value
+                        .unwrap_or(0)
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(digit))
+                        .ok_or_else(invalid)?,
+                );
+            }
+            _ if byte.is_ascii_whitespace() => {
+                if let Some(value) = value.take() {
+                    values.push(T::try_from(value).map_err(|_| invalid())?);
+                }
+            }
+            _ => return Err(invalid()),
+        }
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terminator_across_chunks() {
+        let mut framer = Framer::new();
+
+        framer.push(b"0 1 2\r");
+        framer.push(b"\n.");
+        assert!(!framer.has_frame());
+        framer.push(b"\r\n\r\n.\r\nv1\0.2\r\n.");
+
+        assert_eq!(Some(&b"0 1 2"[..]), framer.next_frame());
+        assert_eq!(Some(&b""[..]), framer.next_frame());
+        assert_eq!(None, framer.next_frame());
+
+        framer.push(b"\r\n");
+        assert_eq!("v1.2", framer.next_response().unwrap().unwrap());
+    }
+
+    #[test]
+    fn test_parse_numerical_vec() {
+        let actual = parse_numerical_vec::<u16>(b"41 65535\r\n0  7").unwrap();
+
+        assert_eq!(vec![41, 65535, 0, 7], actual);
+        assert!(parse_numerical_vec::<u8>(b"256").is_err());
+        assert!(parse_numerical_vec::<u8>(b"1 -2").is_err());
+    }
+}
@@ -11,6 +11,7 @@ use serde::Serialize;
 pub struct Device {
     pub hardware: Hardware,
     pub serial_port: String,
+    pub serial_number: Option<String>,
 }
 
 impl Display for Device {
@@ -121,6 +122,118 @@ pub struct Virtual {
     pub wireless_rf_sync_pairing: Option<VirtualNode>,
 }
 
+impl Virtual {
+    /// Gets every node paired with the Focus command that reads and writes it.
+    ///
+    /// Nodes that the device does not support are `None`.
+    pub fn nodes(&self) -> [(&'static str, Option<VirtualNode>); 75] {
+        [
+            ("version", Some(self.version)),
+            ("keymap.custom", Some(self.keymap_custom)),
+            ("keymap.default", Some(self.keymap_default)),
+            ("keymap.onlyCustom", Some(self.keymap_only_custom)),
+            ("settings.defaultLayer", Some(self.settings_default_layer)),
+            ("settings.valid?", Some(self.settings_valid)),
+            ("settings.version", Some(self.settings_version)),
+            ("settings.crc", Some(self.settings_crc)),
+            ("eeprom.contents", Some(self.eeprom_contents)),
+            ("eeprom.free", Some(self.eeprom_free)),
+            ("led.at", Some(self.led_at)),
+            ("led.setAll", Some(self.led_set_all)),
+            ("led.mode", Some(self.led_mode)),
+            ("led.fade", self.led_fade),
+            ("led.brightness", Some(self.led_brightness)),
+            ("led.brightness.wireless", self.led_brightness_wireless),
+            ("led.brightnessUG", Some(self.led_brightness_ug)),
+            ("led.brightnessUG.wireless", self.led_brightness_ug_wireless),
+            ("led.theme", Some(self.led_theme)),
+            ("palette", Some(self.palette)),
+            ("colormap.map", Some(self.colormap_map)),
+            ("idleleds.time_limit", Some(self.idle_leds_time_limit)),
+            ("idleleds.wireless", self.idle_leds_wireless),
+            ("hardware.version", Some(self.hardware_version)),
+            ("hardware.side_power", Some(self.hardware_side_power)),
+            ("hardware.side_ver", Some(self.hardware_side_ver)),
+            ("hardware.sled_ver", Some(self.hardware_sled_ver)),
+            ("hardware.sled_current", Some(self.hardware_sled_current)),
+            ("hardware.layout", Some(self.hardware_layout)),
+            ("hardware.joint", Some(self.hardware_joint)),
+            ("hardware.keyscan", Some(self.hardware_keyscan)),
+            ("hardware.crc_errors", Some(self.hardware_crc_errors)),
+            ("hardware.firmware", Some(self.hardware_firmware)),
+            ("hardware.chip_id", Some(self.hardware_chip_id)),
+            ("qukeys.holdTimeout", Some(self.qukeys_hold_timeout)),
+            (
+                "qukeys.overlapThreshold",
+                Some(self.qukeys_overlap_threshold),
+            ),
+            ("superkeys.map", Some(self.superkeys_map)),
+            ("superkeys.waitfor", Some(self.superkeys_wait_for)),
+            ("superkeys.timeout", Some(self.superkeys_timeout)),
+            ("superkeys.repeat", Some(self.superkeys_repeat)),
+            ("superkeys.holdstart", Some(self.superkeys_hold_start)),
+            ("superkeys.overlap", Some(self.superkeys_overlap)),
+            ("macros.map", Some(self.macros_map)),
+            ("macros.trigger", Some(self.macros_trigger)),
+            ("macros.memory", Some(self.macros_memory)),
+            ("help", Some(self.help)),
+            ("mouse.speed", Some(self.mouse_speed)),
+            ("mouse.speedDelay", Some(self.mouse_speed_delay)),
+            ("mouse.accelSpeed", Some(self.mouse_accel_speed)),
+            ("mouse.accelDelay", Some(self.mouse_accel_delay)),
+            ("mouse.wheelSpeed", Some(self.mouse_wheel_speed)),
+            ("mouse.wheelDelay", Some(self.mouse_wheel_delay)),
+            ("mouse.speedLimit", Some(self.mouse_speed_limit)),
+            ("layer.activate", Some(self.layer_activate)),
+            ("layer.deactivate", Some(self.layer_deactivate)),
+            ("layer.isActive", Some(self.layer_is_active)),
+            ("layer.moveTo", Some(self.layer_move_to)),
+            ("layer.state", Some(self.layer_state)),
+            (
+                "wireless.battery.left.level",
+                self.wireless_battery_left_level,
+            ),
+            (
+                "wireless.battery.right.level",
+                self.wireless_battery_right_level,
+            ),
+            (
+                "wireless.battery.left.status",
+                self.wireless_battery_left_status,
+            ),
+            (
+                "wireless.battery.right.status",
+                self.wireless_battery_right_status,
+            ),
+            (
+                "wireless.battery.savingMode",
+                self.wireless_battery_saving_mode,
+            ),
+            ("wireless.energy.modes", self.wireless_energy_modes),
+            ("wireless.energy.disable", self.wireless_energy_disable),
+            (
+                "wireless.energy.currentMode",
+                self.wireless_energy_current_mode,
+            ),
+            ("wireless.bluetooth.macs", self.wireless_bluetooth_macs),
+            (
+                "wireless.bluetooth.peerIds",
+                self.wireless_bluetooth_peer_ids,
+            ),
+            ("wireless.bluetooth.remove", self.wireless_bluetooth_remove),
+            (
+                "wireless.bluetooth.deviceName",
+                self.wireless_bluetooth_device_name,
+            ),
+            ("wireless.bluetooth.list", self.wireless_bluetooth_list),
+            ("wireless.rf.power", self.wireless_rf_power),
+            ("wireless.rf.stability", self.wireless_rf_stability),
+            ("wireless.rf.channelHop", self.wireless_rf_channel_hop),
+            ("wireless.rf.syncPairing", self.wireless_rf_sync_pairing),
+        ]
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct VirtualNode {
@@ -199,7 +312,7 @@ pub struct Usb {
     pub product_id: u16,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Grid {
     pub rows: u8,
@@ -218,3 +331,29 @@ pub struct Languages {
 pub struct Dialog {
     pub update_instructions: &'static str,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::types::hardware_virtual::DEVICES_VIRTUAL;
+
+    #[test]
+    fn test_virtual_definitions_match_grid() {
+        for hardware in DEVICES_VIRTUAL.iter() {
+            let virtual_info = hardware.virtual_info.unwrap();
+            let keyboard = hardware.keyboard.unwrap();
+            let underglow = hardware.keyboard_underglow.unwrap();
+            let keys = keyboard.rows as usize * keyboard.columns as usize;
+            let leds = underglow.rows as usize * underglow.columns as usize;
+            let palette_width = if hardware.rgbw_mode { 4 } else { 3 };
+
+            let keymap = virtual_info.keymap_custom.data.split_whitespace().count();
+            let colormap = virtual_info.colormap_map.data.split_whitespace().count();
+            let palette = virtual_info.palette.data.split_whitespace().count();
+
+            assert_eq!(0, keymap % keys, "{}", hardware);
+            assert_eq!(keymap / keys * leds, colormap, "{}", hardware);
+            assert_eq!(16 * palette_width, palette, "{}", hardware);
+            assert_eq!(hardware.wireless, virtual_info.wireless_rf_power.is_some());
+        }
+    }
+}
@@ -1,6 +1,18 @@
 use crate::{color::*, errors::FocusError};
 use std::str::FromStr;
 
+/// Fails when a value is above the upper limit the firmware accepts.
+pub fn check_limit(label: &'static str, max: usize, provided: usize) -> Result<(), FocusError> {
+    if provided > max {
+        return Err(FocusError::ValueAboveLimitError {
+            label,
+            max,
+            provided,
+        });
+    }
+    Ok(())
+}
+
 pub fn string_to_numerical_vec<T: FromStr>(str: &str) -> Result<Vec<T>, FocusError>
 where
     <T as FromStr>::Err: std::fmt::Display,
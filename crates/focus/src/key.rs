@@ -0,0 +1,694 @@
+use crate::errors::FocusError;
+use std::fmt::{Display, Formatter};
+use std::ops::{BitOr, BitOrAssign};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const TRANSPARENT: u16 = 65535;
+
+/// Flags in the high byte of a key that is not a plain keyboard key.
+const SYNTHETIC: u8 = 0x40;
+const RESERVED: u8 = 0x80;
+const IS_CONSUMER: u8 = 0x08;
+const CONSUMER_USAGE_TYPE: u8 = 0x34;
+const LAYER_FLAGS: u8 = SYNTHETIC | 0x04;
+const MOUSE_FLAGS: u8 = SYNTHETIC | 0x10;
+
+const LOCK_LAYER: u16 = 17408;
+const SHIFT_TO_LAYER: u16 = 17450;
+const MOVE_TO_LAYER: u16 = 17492;
+/// How many layers each of the layer ranges can address.
+const LAYER_RANGE: u16 = 32;
+
+const LED_NEXT: u16 = 17152;
+const LED_PREVIOUS: u16 = 17153;
+
+const ONE_SHOT_MODIFIER: u16 = 49153;
+const ONE_SHOT_LAYER: u16 = 49161;
+const ONE_SHOT_LAYER_LAST: u16 = 49168;
+const DUAL_MODIFIER: u16 = 49169;
+const DUAL_LAYER: u16 = 51218;
+/// How many modifiers or layers the dual-function ranges can address.
+const DUAL_RANGE: u16 = 8;
+
+const MACRO: u16 = 53852;
+const SUPERKEY: u16 = 53916;
+const SUPERKEY_LAST: u16 = 54043;
+
+/// The names of the keyboard keys besides letters, digits, function keys and keypad digits.
+///
+/// The first name is displayed, the others are accepted as aliases.
+const KEYBOARD_NAMES: &[(u8, &[&str])] = &[
+    (0x28, &["Enter", "Return"]),
+    (0x29, &["Escape", "Esc"]),
+    (0x2A, &["Backspace", "Bksp"]),
+    (0x2B, &["Tab"]),
+    (0x2C, &["Space", "Spc"]),
+    (0x2D, &["Minus", "-"]),
+    (0x2E, &["Equals", "="]),
+    (0x2F, &["LeftBracket", "["]),
+    (0x30, &["RightBracket", "]"]),
+    (0x31, &["Backslash", "\\"]),
+    (0x32, &["NonUsPound"]),
+    (0x33, &["Semicolon", ";"]),
+    (0x34, &["Quote", "'"]),
+    (0x35, &["Grave", "Backtick", "`"]),
+    (0x36, &["Comma", ","]),
+    (0x37, &["Period", "Dot", "."]),
+    (0x38, &["Slash", "/"]),
+    (0x39, &["CapsLock", "Caps"]),
+    (0x46, &["PrintScreen", "PrtSc"]),
+    (0x47, &["ScrollLock"]),
+    (0x48, &["Pause"]),
+    (0x49, &["Insert", "Ins"]),
+    (0x4A, &["Home"]),
+    (0x4B, &["PageUp", "PgUp"]),
+    (0x4C, &["Delete", "Del"]),
+    (0x4D, &["End"]),
+    (0x4E, &["PageDown", "PgDn"]),
+    (0x4F, &["Right", "RightArrow"]),
+    (0x50, &["Left", "LeftArrow"]),
+    (0x51, &["Down", "DownArrow"]),
+    (0x52, &["Up", "UpArrow"]),
+    (0x53, &["NumLock"]),
+    (0x54, &["KpSlash", "KpDivide"]),
+    (0x55, &["KpAsterisk", "KpMultiply"]),
+    (0x56, &["KpMinus"]),
+    (0x57, &["KpPlus"]),
+    (0x58, &["KpEnter"]),
+    (0x63, &["KpDot", "KpPeriod"]),
+    (0x64, &["NonUsBackslash"]),
+    (0x65, &["Application", "Menu"]),
+    (0x67, &["KpEquals"]),
+    (0xE0, &["LCtrl", "LeftCtrl", "Ctrl", "Control"]),
+    (0xE1, &["LShift", "LeftShift", "Shift"]),
+    (0xE2, &["LAlt", "LeftAlt", "Alt"]),
+    (0xE3, &["LGui", "LeftGui", "Gui", "Win", "Cmd", "Super"]),
+    (0xE4, &["RCtrl", "RightCtrl"]),
+    (0xE5, &["RShift", "RightShift"]),
+    (0xE6, &["RAlt", "RightAlt", "AltGr"]),
+    (0xE7, &["RGui", "RightGui"]),
+];
+
+/// The modifier keys each modifier flag is displayed and parsed as, in display order.
+const MODIFIER_FLAGS: &[(u8, Modifiers)] = &[
+    (0xE0, Modifiers::CTRL),
+    (0xE1, Modifiers::SHIFT),
+    (0xE2, Modifiers::LEFT_ALT),
+    (0xE6, Modifiers::RIGHT_ALT),
+    (0xE3, Modifiers::GUI),
+];
+
+/// The names of the consumer keys, by usage and usage type.
+const CONSUMER_NAMES: &[((u16, u8), &[&str])] = &[
+    ((0xE2, 0x04), &["Mute"]),
+    ((0xE9, 0x14), &["VolumeUp", "VolUp"]),
+    ((0xEA, 0x14), &["VolumeDown", "VolDown"]),
+    ((0xB5, 0x10), &["NextTrack", "Next"]),
+    ((0xB6, 0x10), &["PreviousTrack", "Prev"]),
+    ((0xB7, 0x10), &["Stop"]),
+    ((0xB9, 0x10), &["Shuffle"]),
+    ((0xCD, 0x10), &["PlayPause", "Play"]),
+    ((0x6F, 0x14), &["BrightnessUp"]),
+    ((0x70, 0x14), &["BrightnessDown"]),
+];
+
+/// The names of the mouse keys, by their bits.
+const MOUSE_NAMES: &[(u8, &[&str])] = &[
+    (0x01, &["MouseUp"]),
+    (0x02, &["MouseDown"]),
+    (0x04, &["MouseLeft"]),
+    (0x08, &["MouseRight"]),
+    (0x11, &["MouseWheelUp"]),
+    (0x12, &["MouseWheelDown"]),
+    (0x14, &["MouseWheelLeft"]),
+    (0x18, &["MouseWheelRight"]),
+    (0x41, &["MouseButtonLeft", "MouseClick"]),
+    (0x42, &["MouseButtonRight"]),
+    (0x44, &["MouseButtonMiddle"]),
+    (0x48, &["MouseButtonBack"]),
+    (0x50, &["MouseButtonForward"]),
+];
+
+/// The HID code of the first modifier key, `LCtrl`, followed by `LShift`, `LAlt`, `LGui` and the right hand ones.
+const FIRST_MODIFIER_CODE: u8 = 0xE0;
+
+/// The modifiers held together with a keyboard key, such as `Ctrl` in `Ctrl+C`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    pub const NONE: Modifiers = Modifiers(0);
+    pub const CTRL: Modifiers = Modifiers(0x01);
+    pub const LEFT_ALT: Modifiers = Modifiers(0x02);
+    pub const RIGHT_ALT: Modifiers = Modifiers(0x04);
+    pub const SHIFT: Modifiers = Modifiers(0x08);
+    pub const GUI: Modifiers = Modifiers(0x10);
+
+    const ALL: u8 = 0x1F;
+
+    /// Creates the modifiers from their flags, ignoring bits that are not modifiers.
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits & Self::ALL)
+    }
+
+    /// Gets the flags as they are stored in the high byte of the key.
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Whether no modifier is held.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether every modifier in `other` is held.
+    pub fn contains(self, other: Modifiers) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Modifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// A key as stored in the keymap and the superkeys map.
+///
+/// Every `u16` decodes to a key and encodes back to the same value, codes without a known meaning become `Raw`.
+/// Keys are displayed and parsed by name, and serialized as their name.
+/// Modifier keys of the one-shot and dual-function keys are HID codes, from `0xE0` (`LCtrl`) to `0xE7` (`RGui`).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+pub enum Key {
+    /// Does nothing.
+    #[default]
+    NoKey,
+    /// Falls through to the key on the next active layer below.
+    Transparent,
+    /// A HID keyboard key, optionally sent together with modifiers.
+    Keyboard { code: u8, modifiers: Modifiers },
+    /// Toggles the layer on or off.
+    LockLayer(u8),
+    /// Activates the layer while held.
+    ShiftToLayer(u8),
+    /// Makes the layer the only active one.
+    MoveToLayer(u8),
+    /// Switches to the next LED effect.
+    LedNext,
+    /// Switches to the previous LED effect.
+    LedPrevious,
+    /// A HID consumer control key such as a media key, with the usage type the firmware stores next to the usage.
+    Consumer { usage: u16, usage_type: u8 },
+    /// A mouse key, movement, wheel, warp or buttons depending on the bits.
+    Mouse(u8),
+    /// Holds the modifier for the next key press only.
+    OneShotModifier(u8),
+    /// Activates the layer for the next key press only.
+    OneShotLayer(u8),
+    /// Acts as the key when tapped and as the modifier when held.
+    DualModifier { modifier: u8, code: u8 },
+    /// Acts as the key when tapped and shifts to the layer when held.
+    DualLayer { layer: u8, code: u8 },
+    /// Plays the macro.
+    Macro(u8),
+    /// Runs the superkey from the superkeys map.
+    Superkey(u8),
+    /// A code without a known meaning.
+    Raw(u16),
+}
+
+impl Key {
+    /// Decodes the key stored as the value.
+    pub fn decode(value: u16) -> Self {
+        let [flags, low] = value.to_be_bytes();
+        let key = match value {
+            0 => Key::NoKey,
+            TRANSPARENT => Key::Transparent,
+            _ if flags & !Modifiers::ALL == 0 => Key::Keyboard {
+                code: low,
+                modifiers: Modifiers::from_bits(flags),
+            },
+            _ if flags == LAYER_FLAGS => match value {
+                LOCK_LAYER..SHIFT_TO_LAYER => Key::LockLayer((value - LOCK_LAYER) as u8),
+                SHIFT_TO_LAYER..MOVE_TO_LAYER => Key::ShiftToLayer((value - SHIFT_TO_LAYER) as u8),
+                _ => Key::MoveToLayer(value.wrapping_sub(MOVE_TO_LAYER) as u8),
+            },
+            LED_NEXT => Key::LedNext,
+            LED_PREVIOUS => Key::LedPrevious,
+            _ if flags & (SYNTHETIC | RESERVED | IS_CONSUMER) == SYNTHETIC | IS_CONSUMER => {
+                Key::Consumer {
+                    usage: ((flags as u16 & 0x03) << 8) | low as u16,
+                    usage_type: flags & CONSUMER_USAGE_TYPE,
+                }
+            }
+            _ if flags == MOUSE_FLAGS => Key::Mouse(low),
+            ONE_SHOT_MODIFIER..ONE_SHOT_LAYER => {
+                Key::OneShotModifier(FIRST_MODIFIER_CODE + (value - ONE_SHOT_MODIFIER) as u8)
+            }
+            ONE_SHOT_LAYER..=ONE_SHOT_LAYER_LAST => {
+                Key::OneShotLayer((value - ONE_SHOT_LAYER) as u8)
+            }
+            DUAL_MODIFIER..DUAL_LAYER => {
+                let [modifier, code] = (value - DUAL_MODIFIER).to_be_bytes();
+                Key::DualModifier {
+                    modifier: FIRST_MODIFIER_CODE + modifier,
+                    code,
+                }
+            }
+            _ if (DUAL_LAYER..DUAL_LAYER + (DUAL_RANGE << 8)).contains(&value) => {
+                let [layer, code] = (value - DUAL_LAYER).to_be_bytes();
+                Key::DualLayer { layer, code }
+            }
+            MACRO..SUPERKEY => Key::Macro((value - MACRO) as u8),
+            SUPERKEY..=SUPERKEY_LAST => Key::Superkey((value - SUPERKEY) as u8),
+            _ => Key::Raw(value),
+        };
+
+        // Keys outside of the ranges their variant can encode, such as layers past the last one, stay raw.
+        if key.encode() == value {
+            key
+        } else {
+            Key::Raw(value)
+        }
+    }
+
+    /// Encodes the key as it is stored on the device.
+    ///
+    /// Values out of the range of their variant, such as a `Macro(200)`, wrap into other keys; `decode` never produces them.
+    pub fn encode(self) -> u16 {
+        match self {
+            Key::NoKey => 0,
+            Key::Transparent => TRANSPARENT,
+            Key::Keyboard { code, modifiers } => u16::from_be_bytes([modifiers.bits(), code]),
+            Key::LockLayer(layer) => LOCK_LAYER + (layer as u16 % LAYER_RANGE),
+            Key::ShiftToLayer(layer) => SHIFT_TO_LAYER + (layer as u16 % LAYER_RANGE),
+            Key::MoveToLayer(layer) => MOVE_TO_LAYER + (layer as u16 % LAYER_RANGE),
+            Key::LedNext => LED_NEXT,
+            Key::LedPrevious => LED_PREVIOUS,
+            Key::Consumer { usage, usage_type } => u16::from_be_bytes([
+                SYNTHETIC
+                    | IS_CONSUMER
+                    | (usage_type & CONSUMER_USAGE_TYPE)
+                    | ((usage >> 8) as u8 & 0x03),
+                usage as u8,
+            ]),
+            Key::Mouse(bits) => u16::from_be_bytes([MOUSE_FLAGS, bits]),
+            Key::OneShotModifier(modifier) => ONE_SHOT_MODIFIER + modifier_index(modifier) as u16,
+            Key::OneShotLayer(layer) => ONE_SHOT_LAYER + (layer as u16 % DUAL_RANGE),
+            Key::DualModifier { modifier, code } => {
+                DUAL_MODIFIER + u16::from_be_bytes([modifier_index(modifier), code])
+            }
+            Key::DualLayer { layer, code } => {
+                DUAL_LAYER + u16::from_be_bytes([layer % DUAL_RANGE as u8, code])
+            }
+            Key::Macro(index) => MACRO + (index as u16 % (SUPERKEY - MACRO)),
+            Key::Superkey(index) => SUPERKEY + (index as u16 % (SUPERKEY_LAST - SUPERKEY + 1)),
+            Key::Raw(value) => value,
+        }
+    }
+
+    /// Gets the layer the key activates, moves to or locks, in any of the layer ranges.
+    pub fn layer(self) -> Option<u8> {
+        match self {
+            Key::LockLayer(layer)
+            | Key::ShiftToLayer(layer)
+            | Key::MoveToLayer(layer)
+            | Key::OneShotLayer(layer)
+            | Key::DualLayer { layer, .. } => Some(layer),
+            _ => None,
+        }
+    }
+}
+
+impl From<u16> for Key {
+    fn from(value: u16) -> Self {
+        Key::decode(value)
+    }
+}
+
+impl From<Key> for u16 {
+    fn from(key: Key) -> Self {
+        key.encode()
+    }
+}
+
+/// Displays keys by name, such as `LCtrl+C`, `ShiftToLayer(2)`, `Macro(5)`, `MouseUp` or `Mute`.
+///
+/// Codes without a name are displayed by number, such as `Key(136)` or `Raw(16384)`, so every key can be parsed back.
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match *self {
+            Key::NoKey => write!(f, "NoKey"),
+            Key::Transparent => write!(f, "Transparent"),
+            Key::Keyboard { code, modifiers } => {
+                for &(modifier, flag) in MODIFIER_FLAGS {
+                    if modifiers.contains(flag) {
+                        write!(f, "{}+", KeyboardCode(modifier))?;
+                    }
+                }
+                write!(f, "{}", KeyboardCode(code))
+            }
+            Key::LockLayer(layer) => write!(f, "LockLayer({})", layer),
+            Key::ShiftToLayer(layer) => write!(f, "ShiftToLayer({})", layer),
+            Key::MoveToLayer(layer) => write!(f, "MoveToLayer({})", layer),
+            Key::LedNext => write!(f, "LedNext"),
+            Key::LedPrevious => write!(f, "LedPrevious"),
+            Key::Consumer { usage, usage_type } => {
+                match name_of(CONSUMER_NAMES, (usage, usage_type)) {
+                    Some(name) => write!(f, "{}", name),
+                    None => write!(f, "Raw({})", self.encode()),
+                }
+            }
+            Key::Mouse(bits) => match name_of(MOUSE_NAMES, bits) {
+                Some(name) => write!(f, "{}", name),
+                None => write!(f, "Mouse({})", bits),
+            },
+            Key::OneShotModifier(modifier) => {
+                write!(f, "OneShotModifier({})", KeyboardCode(modifier))
+            }
+            Key::OneShotLayer(layer) => write!(f, "OneShotLayer({})", layer),
+            Key::DualModifier { modifier, code } => write!(
+                f,
+                "DualModifier({}, {})",
+                KeyboardCode(modifier),
+                KeyboardCode(code)
+            ),
+            Key::DualLayer { layer, code } => {
+                write!(f, "DualLayer({}, {})", layer, KeyboardCode(code))
+            }
+            Key::Macro(index) => write!(f, "Macro({})", index),
+            Key::Superkey(index) => write!(f, "Superkey({})", index),
+            Key::Raw(value) => write!(f, "Raw({})", value),
+        }
+    }
+}
+
+/// Parses the names `Display` produces, ignoring case and accepting aliases such as `Esc` for `Escape`.
+///
+/// Modifiers are joined with `+`, where `Ctrl`, `Shift`, `Alt`, `AltGr` and `Gui` are held together with the last key.
+impl FromStr for Key {
+    type Err = FocusError;
+
+    fn from_str(s: &str) -> Result<Self, FocusError> {
+        let error = || FocusError::ParseKeyError {
+            name: s.to_string(),
+        };
+        let mut parts = s.split('+').map(str::trim).collect::<Vec<&str>>();
+        // `+` only joins modifiers, the plus key itself is `Shift+=` or `KpPlus`.
+        let last = parts
+            .pop()
+            .filter(|last| !last.is_empty())
+            .ok_or_else(error)?;
+        let key = parse_key(last).ok_or_else(error)?;
+        if parts.is_empty() {
+            return Ok(key);
+        }
+
+        let Key::Keyboard {
+            code,
+            mut modifiers,
+        } = key
+        else {
+            return Err(error());
+        };
+        for part in parts {
+            let modifier = parse_keyboard_code(part).ok_or_else(error)?;
+            let &(_, flag) = MODIFIER_FLAGS
+                .iter()
+                .find(|&&(code, _)| code == modifier)
+                .ok_or_else(error)?;
+            modifiers |= flag;
+        }
+        Ok(Key::Keyboard { code, modifiers })
+    }
+}
+
+/// Parses a key without modifiers.
+fn parse_key(s: &str) -> Option<Key> {
+    if let Some((name, arguments)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+        let arguments = arguments
+            .splitn(2, ',')
+            .map(str::trim)
+            .collect::<Vec<&str>>();
+        let number = |index: usize| arguments.get(index)?.parse::<u8>().ok();
+        let code = |index: usize| parse_keyboard_code(arguments.get(index)?);
+        let key = match name.trim().to_ascii_lowercase().as_str() {
+            "key" => {
+                return Some(Key::Keyboard {
+                    code: parse_keyboard_code(s)?,
+                    modifiers: Modifiers::NONE,
+                })
+            }
+            "locklayer" => Key::LockLayer(number(0)?),
+            "shifttolayer" => Key::ShiftToLayer(number(0)?),
+            "movetolayer" => Key::MoveToLayer(number(0)?),
+            "mouse" => Key::Mouse(number(0)?),
+            "oneshotmodifier" => Key::OneShotModifier(code(0)?),
+            "oneshotlayer" => Key::OneShotLayer(number(0)?),
+            "dualmodifier" => Key::DualModifier {
+                modifier: code(0)?,
+                code: code(1)?,
+            },
+            "duallayer" => Key::DualLayer {
+                layer: number(0)?,
+                code: code(1)?,
+            },
+            "macro" => Key::Macro(number(0)?),
+            "superkey" => Key::Superkey(number(0)?),
+            "raw" => return arguments.first()?.parse::<u16>().ok().map(Key::decode),
+            _ => return None,
+        };
+        // Only keys that survive encoding are accepted, so `Macro(200)` is an error rather than another key.
+        return (Key::decode(key.encode()) == key).then_some(key);
+    }
+
+    match s.to_ascii_lowercase().as_str() {
+        "nokey" | "none" => return Some(Key::NoKey),
+        "transparent" | "trans" => return Some(Key::Transparent),
+        "lednext" => return Some(Key::LedNext),
+        "ledprevious" | "ledprev" => return Some(Key::LedPrevious),
+        _ => {}
+    }
+    if let Some((usage, usage_type)) = find_name(CONSUMER_NAMES, s) {
+        return Some(Key::Consumer { usage, usage_type });
+    }
+    if let Some(bits) = find_name(MOUSE_NAMES, s) {
+        return Some(Key::Mouse(bits));
+    }
+    parse_keyboard_code(s).map(|code| Key::Keyboard {
+        code,
+        modifiers: Modifiers::NONE,
+    })
+}
+
+/// Parses the name of a keyboard key, or `Key(n)` for keys without one.
+fn parse_keyboard_code(s: &str) -> Option<u8> {
+    let lowercase = s.to_ascii_lowercase();
+    let numbered = |prefix: &str| lowercase.strip_prefix(prefix)?.parse::<u8>().ok();
+    match lowercase.as_bytes() {
+        [letter @ b'a'..=b'z'] => return Some(letter - b'a' + 0x04),
+        [b'0'] => return Some(0x27),
+        [digit @ b'1'..=b'9'] => return Some(digit - b'1' + 0x1E),
+        _ => {}
+    }
+    match (numbered("f"), numbered("kp")) {
+        (Some(n @ 1..=12), _) => return Some(n - 1 + 0x3A),
+        (Some(n @ 13..=24), _) => return Some(n - 13 + 0x68),
+        (_, Some(0)) => return Some(0x62),
+        (_, Some(n @ 1..=9)) => return Some(n - 1 + 0x59),
+        _ => {}
+    }
+    if let Some(code) = find_name(KEYBOARD_NAMES, s) {
+        return Some(code);
+    }
+    lowercase
+        .strip_prefix("key(")?
+        .strip_suffix(')')?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Displays a keyboard key by name, or as `Key(n)` when it has none.
+struct KeyboardCode(u8);
+
+impl Display for KeyboardCode {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self.0 {
+            code @ 0x04..=0x1D => write!(f, "{}", (b'A' + code - 0x04) as char),
+            code @ 0x1E..=0x26 => write!(f, "{}", code - 0x1D),
+            0x27 => write!(f, "0"),
+            code @ 0x3A..=0x45 => write!(f, "F{}", code - 0x39),
+            code @ 0x68..=0x73 => write!(f, "F{}", code - 0x5B),
+            code @ 0x59..=0x61 => write!(f, "Kp{}", code - 0x58),
+            0x62 => write!(f, "Kp0"),
+            code => match name_of(KEYBOARD_NAMES, code) {
+                Some(name) => write!(f, "{}", name),
+                None => write!(f, "Key({})", code),
+            },
+        }
+    }
+}
+
+fn name_of<T: PartialEq>(names: &[(T, &[&'static str])], value: T) -> Option<&'static str> {
+    names
+        .iter()
+        .find(|(candidate, _)| *candidate == value)
+        .map(|(_, names)| names[0])
+}
+
+fn find_name<T: Copy>(names: &[(T, &[&str])], name: &str) -> Option<T> {
+    names
+        .iter()
+        .find(|(_, aliases)| aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name)))
+        .map(|&(value, _)| value)
+}
+
+impl From<Key> for String {
+    fn from(key: Key) -> Self {
+        key.to_string()
+    }
+}
+
+impl TryFrom<String> for Key {
+    type Error = FocusError;
+
+    fn try_from(value: String) -> Result<Self, FocusError> {
+        value.parse()
+    }
+}
+
+/// Gets the position of the modifier among the eight modifier keys, as the one-shot and dual-function ranges count them.
+fn modifier_index(modifier: u8) -> u8 {
+    modifier.wrapping_sub(FIRST_MODIFIER_CODE) % DUAL_RANGE as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_virtual::DEVICES_VIRTUAL;
+
+    #[test]
+    fn test_key_decode() {
+        assert_eq!(Key::NoKey, Key::from(0));
+        assert_eq!(Key::Transparent, Key::from(65535));
+        assert_eq!(
+            Key::Keyboard {
+                code: 0x06,
+                modifiers: Modifiers::CTRL
+            },
+            Key::from(262)
+        );
+        assert_eq!(
+            Key::Keyboard {
+                code: 0x19,
+                modifiers: Modifiers::SHIFT | Modifiers::GUI
+            },
+            Key::from(6169)
+        );
+        assert_eq!(Key::LockLayer(0), Key::from(17408));
+        assert_eq!(Key::ShiftToLayer(2), Key::from(17452));
+        assert_eq!(Key::MoveToLayer(1), Key::from(17493));
+        assert_eq!(Key::LedNext, Key::from(17152));
+        assert_eq!(
+            Key::Consumer {
+                usage: 0xE9,
+                usage_type: 0x14
+            },
+            Key::from(23785)
+        );
+        assert_eq!(Key::Mouse(0x01), Key::from(20481));
+        assert_eq!(Key::OneShotModifier(0xE1), Key::from(49154));
+        assert_eq!(Key::OneShotLayer(1), Key::from(49162));
+        assert_eq!(
+            Key::DualModifier {
+                modifier: 0xE0,
+                code: 0x29
+            },
+            Key::from(49210)
+        );
+        assert_eq!(
+            Key::DualLayer {
+                layer: 1,
+                code: 0x28
+            },
+            Key::from(51514)
+        );
+        assert_eq!(Key::Macro(11), Key::from(53863));
+        assert_eq!(Key::Superkey(0), Key::from(53916));
+        assert_eq!(Key::Raw(17440), Key::from(17440));
+    }
+
+    #[test]
+    fn test_key_round_trip() {
+        for hardware in DEVICES_VIRTUAL.iter() {
+            let virtual_info = hardware.virtual_info.unwrap();
+            for node in [
+                virtual_info.keymap_custom,
+                virtual_info.keymap_default,
+                virtual_info.superkeys_map,
+            ] {
+                for value in node.data.split_whitespace() {
+                    let value = value.parse::<u16>().unwrap();
+                    assert_eq!(value, u16::from(Key::from(value)), "{}", hardware);
+                }
+            }
+        }
+        for value in 0..=u16::MAX {
+            assert_eq!(value, Key::decode(value).encode());
+        }
+    }
+
+    #[test]
+    fn test_key_names() {
+        let names = [
+            (262, "LCtrl+C"),
+            (6169, "LShift+LGui+V"),
+            (17452, "ShiftToLayer(2)"),
+            (53857, "Macro(5)"),
+            (53919, "Superkey(3)"),
+            (20481, "MouseUp"),
+            (19682, "Mute"),
+            (49210, "DualModifier(LCtrl, Escape)"),
+            (51514, "DualLayer(1, Enter)"),
+            (136, "Key(136)"),
+            (17440, "Raw(17440)"),
+        ];
+        for (value, name) in names {
+            assert_eq!(name, Key::from(value).to_string());
+            assert_eq!(Key::from(value), name.parse().unwrap());
+        }
+
+        assert_eq!("Escape".parse::<Key>().unwrap(), "esc".parse().unwrap());
+        assert_eq!(
+            "LCtrl+C".parse::<Key>().unwrap(),
+            "Ctrl + c".parse().unwrap()
+        );
+        assert_eq!(Key::from(0x2E), "=".parse().unwrap());
+        assert!("Macro(200)".parse::<Key>().is_err());
+        assert!("RCtrl+C".parse::<Key>().is_err());
+        assert!("Hyper".parse::<Key>().is_err());
+
+        for value in 0..=u16::MAX {
+            let key = Key::decode(value);
+            assert_eq!(key, key.to_string().parse().unwrap(), "{}", key);
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::errors::FocusError;
+use crate::hardware::{Device, Grid, Hardware};
+use crate::key::Key;
+
+/// Where a key sits in the keymap.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub layer: usize,
+    pub row: usize,
+    pub column: usize,
+}
+
+/// A keymap such as `Settings::keymap_custom`, addressed by layer, row and column.
+///
+/// The device stores the layers one after another, each row by row following the keyboard grid of the hardware.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    grid: Grid,
+    keys: Vec<Key>,
+}
+
+impl Keymap {
+    /// Creates the keymap from the flat values, laid out on the keyboard grid of the hardware.
+    pub fn new(hardware: &Hardware, data: &[u16]) -> Result<Self, FocusError> {
+        let grid = hardware.keyboard.ok_or(FocusError::NoKeyboardGridError {
+            name: hardware.info.display_name,
+        })?;
+        Self::with_grid(grid, data)
+    }
+
+    /// Creates the keymap from the flat values, laid out on the keyboard grid of the device.
+    pub fn from_device(device: &Device, data: &[u16]) -> Result<Self, FocusError> {
+        Self::new(&device.hardware, data)
+    }
+
+    /// Creates the keymap from the flat values, laid out on the grid.
+    pub fn with_grid(grid: Grid, data: &[u16]) -> Result<Self, FocusError> {
+        let layer_size = grid.rows as usize * grid.columns as usize;
+        if layer_size == 0 || !data.len().is_multiple_of(layer_size) {
+            return Err(FocusError::KeymapLengthError {
+                length: data.len(),
+                layer_size,
+            });
+        }
+
+        Ok(Self {
+            grid,
+            keys: data.iter().copied().map(Key::from).collect(),
+        })
+    }
+
+    /// Gets the grid each layer is laid out on.
+    pub fn grid(&self) -> Grid {
+        self.grid
+    }
+
+    /// Gets the number of keys in a layer.
+    pub fn layer_size(&self) -> usize {
+        self.grid.rows as usize * self.grid.columns as usize
+    }
+
+    /// Gets the number of layers, inferred from the length of the keymap.
+    pub fn layer_count(&self) -> usize {
+        self.keys.len() / self.layer_size()
+    }
+
+    /// Gets the keys of the layer, row by row.
+    pub fn layer(&self, layer: usize) -> Option<&[Key]> {
+        let size = self.layer_size();
+        self.keys.get(layer * size..(layer + 1) * size)
+    }
+
+    /// Gets the keys of the layer, row by row, to change them.
+    pub fn layer_mut(&mut self, layer: usize) -> Option<&mut [Key]> {
+        let size = self.layer_size();
+        self.keys.get_mut(layer * size..(layer + 1) * size)
+    }
+
+    /// Iterates over the layers, each row by row.
+    pub fn layers(&self) -> impl Iterator<Item = &[Key]> {
+        self.keys.chunks(self.layer_size())
+    }
+
+    /// Gets the key at the position, `None` when it is outside of the keymap.
+    pub fn get(&self, layer: usize, row: usize, column: usize) -> Option<Key> {
+        self.index(layer, row, column).map(|index| self.keys[index])
+    }
+
+    /// Sets the key at the position.
+    pub fn set(
+        &mut self,
+        layer: usize,
+        row: usize,
+        column: usize,
+        key: Key,
+    ) -> Result<(), FocusError> {
+        let index = self.index(layer, row, column).ok_or_else(|| {
+            let (label, max, provided) = if layer >= self.layer_count() {
+                ("layer", self.layer_count().saturating_sub(1), layer)
+            } else if row >= self.grid.rows as usize {
+                ("row", self.grid.rows as usize - 1, row)
+            } else {
+                ("column", self.grid.columns as usize - 1, column)
+            };
+            FocusError::ValueAboveLimitError {
+                label,
+                max,
+                provided,
+            }
+        })?;
+        self.keys[index] = key;
+        Ok(())
+    }
+
+    /// Iterates over every key with its position, layer by layer and row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, Key)> + '_ {
+        let columns = self.grid.columns as usize;
+        let layer_size = self.layer_size();
+        self.keys.iter().enumerate().map(move |(index, &key)| {
+            let position = Position {
+                layer: index / layer_size,
+                row: index % layer_size / columns,
+                column: index % columns,
+            };
+            (position, key)
+        })
+    }
+
+    /// Gets the flat values, as `keymap_custom_set` takes them.
+    pub fn to_vec(&self) -> Vec<u16> {
+        self.keys.iter().map(|&key| key.encode()).collect()
+    }
+
+    fn index(&self, layer: usize, row: usize, column: usize) -> Option<usize> {
+        let columns = self.grid.columns as usize;
+        (layer < self.layer_count() && row < self.grid.rows as usize && column < columns)
+            .then(|| layer * self.layer_size() + row * columns + column)
+    }
+}
+
+impl From<Keymap> for Vec<u16> {
+    fn from(keymap: Keymap) -> Self {
+        keymap.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_virtual::{DEFY_WIRED_VIRTUAL, DEVICES_VIRTUAL};
+
+    fn parse(data: &str) -> Vec<u16> {
+        data.split_whitespace()
+            .map(|value| value.parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_keymap_round_trip() {
+        for hardware in DEVICES_VIRTUAL.iter() {
+            let data = parse(hardware.virtual_info.unwrap().keymap_custom.data);
+            let keymap = Keymap::new(hardware, &data).unwrap();
+            let grid = hardware.keyboard.unwrap();
+
+            assert_eq!(
+                data.len() / (grid.rows as usize * grid.columns as usize),
+                keymap.layer_count()
+            );
+            assert_eq!(data, keymap.to_vec(), "{}", hardware);
+            assert!(keymap.iter().all(|(position, key)| {
+                keymap.get(position.layer, position.row, position.column) == Some(key)
+            }));
+        }
+    }
+
+    #[test]
+    fn test_keymap_addressing() {
+        let data = parse(DEFY_WIRED_VIRTUAL.virtual_info.unwrap().keymap_custom.data);
+        let mut keymap = Keymap::new(&DEFY_WIRED_VIRTUAL, &data).unwrap();
+
+        keymap.set(1, 2, 3, Key::Macro(4)).unwrap();
+
+        assert_eq!(Some(Key::Macro(4)), keymap.get(1, 2, 3));
+        assert_eq!(Key::Macro(4), keymap.layer(1).unwrap()[2 * 16 + 3]);
+        assert_eq!(53856, keymap.to_vec()[80 + 2 * 16 + 3]);
+        assert_eq!(None, keymap.get(10, 0, 0));
+        assert!(keymap.set(0, 5, 0, Key::NoKey).is_err());
+        assert!(Keymap::new(&DEFY_WIRED_VIRTUAL, &data[1..]).is_err());
+    }
+}
@@ -0,0 +1,311 @@
+use crate::errors::FocusError;
+use crate::hardware::{Grid, Hardware};
+use crate::key::{Key, Modifiers};
+use crate::settings::Settings;
+use crate::MAX_LAYERS;
+
+/// Layer level changes to the settings, keeping everything that depends on the layer order consistent.
+///
+/// Each operation moves the layers of the custom keymap and the color map together,
+/// and rewrites the keys referring to a moved layer, such as `ShiftToLayer` or `DualLayer`, in the keymap and the superkeys map.
+/// The default layer follows its layer too.
+/// Keys referring to a deleted layer lose their layer action, a `DualLayer` keeps its key and the others become `NoKey`.
+///
+/// When an operation fails nothing is changed.
+#[derive(Debug)]
+pub struct LayerEditor<'a> {
+    settings: &'a mut Settings,
+    keys_per_layer: usize,
+    leds_per_layer: usize,
+}
+
+impl<'a> LayerEditor<'a> {
+    /// Edits the layers of the settings, laid out on the grids of the hardware.
+    pub fn new(settings: &'a mut Settings, hardware: &Hardware) -> Result<Self, FocusError> {
+        let no_grid = || FocusError::NoKeyboardGridError {
+            name: hardware.info.display_name,
+        };
+        let keys_per_layer = grid_size(hardware.keyboard.ok_or_else(no_grid)?);
+        let leds_per_layer = grid_size(hardware.keyboard_underglow.ok_or_else(no_grid)?);
+
+        let length = settings.keymap_custom.len();
+        if keys_per_layer == 0 || !length.is_multiple_of(keys_per_layer) {
+            return Err(FocusError::KeymapLengthError {
+                length,
+                layer_size: keys_per_layer,
+            });
+        }
+        let expected = length / keys_per_layer * leds_per_layer;
+        if settings.color_map.len() != expected {
+            return Err(FocusError::ColorMapLengthError {
+                length: settings.color_map.len(),
+                expected,
+            });
+        }
+
+        Ok(Self {
+            settings,
+            keys_per_layer,
+            leds_per_layer,
+        })
+    }
+
+    /// Gets the number of custom layers.
+    pub fn layer_count(&self) -> usize {
+        self.settings.keymap_custom.len() / self.keys_per_layer
+    }
+
+    /// Swaps the two layers.
+    pub fn swap(&mut self, first: usize, second: usize) -> Result<(), FocusError> {
+        self.check_layer(first)?;
+        self.check_layer(second)?;
+        let mut sources = self.identity();
+        sources.swap(first, second);
+        self.rearrange(&sources)
+    }
+
+    /// Moves the layer to the new index, shifting the layers in between.
+    pub fn move_layer(&mut self, from: usize, to: usize) -> Result<(), FocusError> {
+        self.check_layer(from)?;
+        self.check_layer(to)?;
+        let mut sources = self.identity();
+        let layer = sources.remove(from);
+        sources.insert(to, layer);
+        self.rearrange(&sources)
+    }
+
+    /// Inserts a copy of the layer right after it, references keep pointing at the original.
+    pub fn duplicate(&mut self, layer: usize) -> Result<(), FocusError> {
+        self.check_layer(layer)?;
+        let mut sources = self.identity();
+        sources.insert(layer + 1, Some(layer));
+        self.rearrange(&sources)
+    }
+
+    /// Inserts a layer of transparent keys at the index, shifting the layers after it.
+    ///
+    /// The index may be the layer count, to add the layer at the end.
+    pub fn insert(&mut self, at: usize) -> Result<(), FocusError> {
+        if at > self.layer_count() {
+            return Err(FocusError::ValueAboveLimitError {
+                label: "layer",
+                max: self.layer_count(),
+                provided: at,
+            });
+        }
+        let mut sources = self.identity();
+        sources.insert(at, None);
+        self.rearrange(&sources)
+    }
+
+    /// Deletes the layer, shifting the layers after it.
+    pub fn delete(&mut self, layer: usize) -> Result<(), FocusError> {
+        self.check_layer(layer)?;
+        let mut sources = self.identity();
+        sources.remove(layer);
+        self.rearrange(&sources)
+    }
+
+    fn identity(&self) -> Vec<Option<usize>> {
+        (0..self.layer_count()).map(Some).collect()
+    }
+
+    fn check_layer(&self, layer: usize) -> Result<(), FocusError> {
+        if layer < self.layer_count() {
+            Ok(())
+        } else {
+            Err(FocusError::ValueAboveLimitError {
+                label: "layer",
+                max: self.layer_count().saturating_sub(1),
+                provided: layer,
+            })
+        }
+    }
+
+    /// Rebuilds the layers, each new layer is a copy of its source layer or a new one for `None`.
+    fn rearrange(&mut self, sources: &[Option<usize>]) -> Result<(), FocusError> {
+        if sources.len() > MAX_LAYERS as usize + 1 {
+            return Err(FocusError::ValueAboveLimitError {
+                label: "layer count",
+                max: MAX_LAYERS as usize + 1,
+                provided: sources.len(),
+            });
+        }
+
+        // Where each old layer ends up, a duplicated layer is referenced by its first copy.
+        let mut targets = vec![None; self.layer_count()];
+        for (target, source) in sources.iter().enumerate() {
+            if let Some(source) = *source {
+                targets[source].get_or_insert(target);
+            }
+        }
+
+        let settings = &*self.settings;
+        let keymap_custom = layered(
+            &settings.keymap_custom,
+            self.keys_per_layer,
+            sources,
+            Key::Transparent.encode(),
+        )
+        .into_iter()
+        .map(|value| retarget(value, &targets))
+        .collect::<Result<Vec<u16>, FocusError>>()?;
+        let superkeys_map = settings
+            .superkeys_map
+            .iter()
+            .map(|&value| retarget(value, &targets))
+            .collect::<Result<Vec<u16>, FocusError>>()?;
+        let color_map = layered(&settings.color_map, self.leds_per_layer, sources, 0);
+        let default_layer = targets
+            .get(settings.settings_default_layer as usize)
+            .map(|target| target.unwrap_or(0) as u8)
+            .unwrap_or(settings.settings_default_layer);
+
+        self.settings.keymap_custom = keymap_custom;
+        self.settings.superkeys_map = superkeys_map;
+        self.settings.color_map = color_map;
+        self.settings.settings_default_layer = default_layer;
+        Ok(())
+    }
+}
+
+fn grid_size(grid: Grid) -> usize {
+    grid.rows as usize * grid.columns as usize
+}
+
+/// Builds the layers from copies of the source layers, filling new layers with `empty`.
+fn layered<T: Copy>(data: &[T], layer_size: usize, sources: &[Option<usize>], empty: T) -> Vec<T> {
+    sources
+        .iter()
+        .flat_map(|source| match source {
+            Some(source) => data[source * layer_size..(source + 1) * layer_size].to_vec(),
+            None => vec![empty; layer_size],
+        })
+        .collect()
+}
+
+/// Points a key referring to a custom layer at where that layer ended up.
+fn retarget(value: u16, targets: &[Option<usize>]) -> Result<u16, FocusError> {
+    let key = Key::decode(value);
+    let Some(layer) = key
+        .layer()
+        .filter(|&layer| (layer as usize) < targets.len())
+    else {
+        return Ok(value);
+    };
+
+    let retargeted = match targets[layer as usize] {
+        Some(target) => {
+            let target = target as u8;
+            match key {
+                Key::LockLayer(_) => Key::LockLayer(target),
+                Key::ShiftToLayer(_) => Key::ShiftToLayer(target),
+                Key::MoveToLayer(_) => Key::MoveToLayer(target),
+                Key::OneShotLayer(_) => Key::OneShotLayer(target),
+                Key::DualLayer { code, .. } => Key::DualLayer {
+                    layer: target,
+                    code,
+                },
+                _ => key,
+            }
+        }
+        None => match key {
+            Key::DualLayer { code, .. } => Key::Keyboard {
+                code,
+                modifiers: Modifiers::NONE,
+            },
+            _ => Key::NoKey,
+        },
+    };
+
+    // One-shot and dual-function keys only reach the first layers.
+    if Key::decode(retargeted.encode()) == retargeted {
+        Ok(retargeted.encode())
+    } else {
+        Err(FocusError::LayerReferenceError {
+            key: key.to_string(),
+            layer: retargeted.layer().unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_virtual::DEFY_WIRED_VIRTUAL;
+    use crate::prelude::*;
+
+    fn settings() -> Settings {
+        Focus::new_virtual(&DEFY_WIRED_VIRTUAL)
+            .unwrap()
+            .settings_get()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_layer_swap_fixes_references() {
+        let original = settings();
+        let mut settings = original.clone();
+        settings.superkeys_map[0] = Key::ShiftToLayer(1).encode();
+        settings.settings_default_layer = 1;
+        let mut layers = LayerEditor::new(&mut settings, &DEFY_WIRED_VIRTUAL).unwrap();
+
+        layers.swap(1, 2).unwrap();
+
+        assert_eq!(
+            original.keymap_custom[80..160],
+            settings.keymap_custom[160..240]
+        );
+        assert_eq!(original.color_map[178..356], settings.color_map[356..534]);
+        assert_eq!(Key::ShiftToLayer(2), Key::from(settings.superkeys_map[0]));
+        assert_eq!(2, settings.settings_default_layer);
+        for (before, after) in original.keymap_custom[..80]
+            .iter()
+            .zip(&settings.keymap_custom[..80])
+        {
+            let expected = match Key::from(*before) {
+                Key::ShiftToLayer(1) => Key::ShiftToLayer(2),
+                Key::ShiftToLayer(2) => Key::ShiftToLayer(1),
+                Key::MoveToLayer(1) => Key::MoveToLayer(2),
+                Key::MoveToLayer(2) => Key::MoveToLayer(1),
+                Key::OneShotLayer(1) => Key::OneShotLayer(2),
+                Key::DualLayer { layer: 1, code } => Key::DualLayer { layer: 2, code },
+                Key::DualLayer { layer: 2, code } => Key::DualLayer { layer: 1, code },
+                key => key,
+            };
+            assert_eq!(expected, Key::from(*after));
+        }
+    }
+
+    #[test]
+    fn test_layer_insert_and_delete() {
+        let original = settings();
+        let mut settings = original.clone();
+        settings.keymap_custom[0] = Key::DualLayer {
+            layer: 2,
+            code: 0x28,
+        }
+        .encode();
+        settings.keymap_custom[1] = Key::MoveToLayer(2).encode();
+        let mut layers = LayerEditor::new(&mut settings, &DEFY_WIRED_VIRTUAL).unwrap();
+
+        assert!(layers.insert(0).is_err());
+        assert!(layers.duplicate(0).is_err());
+        layers.delete(2).unwrap();
+        layers.insert(0).unwrap();
+        assert_eq!(10, layers.layer_count());
+
+        assert!(settings.keymap_custom[..80]
+            .iter()
+            .all(|&value| Key::from(value) == Key::Transparent));
+        assert_eq!(
+            Key::Keyboard {
+                code: 0x28,
+                modifiers: Modifiers::NONE
+            },
+            Key::from(settings.keymap_custom[80])
+        );
+        assert_eq!(Key::NoKey, Key::from(settings.keymap_custom[81]));
+        assert_eq!(original.color_map[534..], settings.color_map[534..]);
+    }
+}
@@ -1,30 +1,78 @@
-use crate::hardware::Device;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::builder::FocusBuilder;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::cancel::CancelHandle;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::framer::Framer;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::hardware::{Device, Hardware};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::transport::simulator::VirtualFocus;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::transport::Transport;
+#[cfg(not(target_arch = "wasm32"))]
 use errors::FocusError;
-use log::trace;
-use serialport::{SerialPort, SerialPortInfo, SerialPortType};
-use std::str;
+#[cfg(not(target_arch = "wasm32"))]
+use log::{debug, trace};
+#[cfg(not(target_arch = "wasm32"))]
+use serialport::{SerialPortInfo, SerialPortType};
 use std::time::Duration;
 
 pub mod api;
+#[cfg(all(feature = "async", not(target_arch = "wasm32")))]
+pub mod asynchronous;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod builder;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cancel;
 pub mod color;
+pub(crate) mod commands;
+pub mod diagnostics;
 pub mod enums;
 pub mod errors;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod exchange;
+#[cfg(unix)]
+pub mod focusd;
+pub mod framer;
 pub mod hardware;
 pub mod helpers;
+pub mod key;
+pub mod keymap;
+pub mod layers;
 pub mod platform;
 pub mod prelude;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod resilient;
+pub mod response;
+#[cfg(all(feature = "server", not(target_arch = "wasm32")))]
+pub mod server;
 pub mod settings;
+#[cfg(target_os = "linux")]
+pub mod sniffer;
+pub mod superkeys;
+pub mod transport;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod watcher;
 
 #[cfg(unix)]
 use crate::platform::posix::Focus;
 #[cfg(windows)]
 use crate::platform::windows::Focus;
-#[cfg(target_arch = "wasm32")]
-use crate::platform::wasm::Focus;
 
 pub const MAX_LAYERS: u8 = 10 - 1;
 
+/// The baud rate used to talk to the device.
+pub const DEFAULT_BAUD_RATE: u32 = 115_200;
+
+/// How long a single read from the serial port may block before timing out.
+pub const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The initial capacity of the response buffer in bytes.
+pub const DEFAULT_BUFFER_CAPACITY: usize = 1_024 * 8;
+
 /// Constructors
+#[cfg(not(target_arch = "wasm32"))]
 impl Focus {
     /// Find all supported devices.
     pub fn find_all_devices() -> Result<Vec<Device>, FocusError> {
@@ -50,6 +98,7 @@ impl Focus {
                                     Some(Device {
                                         hardware: device.to_owned(),
                                         serial_port: port.port_name.to_owned(),
+                                        serial_number: info.serial_number.to_owned(),
                                     })
                                 } else {
                                     None
@@ -80,41 +129,47 @@ impl Focus {
         Ok(device)
     }
 
+    /// Creates a builder to configure the connection to the device.
+    pub fn builder() -> FocusBuilder {
+        FocusBuilder::new()
+    }
+
     /// Creates a new instance of the Focus API, connecting to the device via the named serial port.
     pub fn new_via_port(port: &str) -> Result<Self, FocusError> {
-        let port_settings = serialport::new(port, 115_200)
-            .data_bits(serialport::DataBits::Eight)
-            .flow_control(serialport::FlowControl::None)
-            .parity(serialport::Parity::None)
-            .stop_bits(serialport::StopBits::One)
-            .timeout(Duration::from_secs(5));
-        let mut serial = port_settings
-            .open_native()
-            .map_err(FocusError::SerialPortOpenError)?;
-        serial
-            .write_data_terminal_ready(true)
-            .map_err(FocusError::SerialPortConfigurationError)?;
-        #[cfg(unix)]
-        serial
-            .set_exclusive(false)
-            .map_err(FocusError::SerialPortConfigurationError)?;
-        Ok(Self {
-            serial,
-            response_buffer: Vec::with_capacity(1_024 * 8),
-        })
+        FocusBuilder::new().port(port).build()
+    }
+
+    /// Creates a new instance of the Focus API, talking to the device over the provided transport.
+    ///
+    /// The timeout the transport reports is applied to it, so reads on a socket without one don't block forever.
+    pub fn new_via_transport(transport: impl Transport + 'static) -> Self {
+        let mut transport = Box::new(transport);
+        if let Err(e) = transport.set_timeout(transport.timeout()) {
+            debug!("Failed to apply the read timeout: {}", e);
+        }
+        Self {
+            transport,
+            framer: Framer::with_capacity(DEFAULT_BUFFER_CAPACITY),
+            command: String::new(),
+            command_timeout: None,
+            cancel: CancelHandle::default(),
+        }
+    }
+
+    /// Creates a new instance of the Focus API, connected to a simulated keyboard built from the virtual hardware definition.
+    ///
+    /// See `hardware::types::hardware_virtual::DEVICES_VIRTUAL` for the available devices.
+    pub fn new_virtual(hardware: &Hardware) -> Result<Self, FocusError> {
+        Ok(Self::new_via_transport(VirtualFocus::new(hardware)?))
     }
 
     /// Creates a new instance of the Focus API, connecting to the device via a reference to the device struct.
     pub fn new_via_device(device: &Device) -> Result<Self, FocusError> {
-        Self::new_via_port(&device.serial_port)
+        FocusBuilder::new().device(device).build()
     }
 
     /// Creates a new instance of the Focus API, connecting to the device via first available device.
     pub fn new_first_available() -> Result<Self, FocusError> {
-        Self::new_via_device(
-            Self::find_all_devices()?
-                .first()
-                .ok_or(FocusError::NoDevicesDetectedError)?,
-        )
+        FocusBuilder::new().build()
     }
 }
@@ -1,6 +1,14 @@
+use crate::cancel::CancelHandle;
+use crate::framer::Framer;
+use crate::transport::Transport;
+use std::time::Duration;
+
 /// The Dygma Focus API.
 #[derive(Debug)]
 pub struct Focus {
-    pub(crate) serial: serialport::TTYPort,
-    pub(crate) response_buffer: Vec<u8>,
+    pub(crate) transport: Box<dyn Transport>,
+    pub(crate) framer: Framer,
+    pub(crate) command: String,
+    pub(crate) command_timeout: Option<Duration>,
+    pub(crate) cancel: CancelHandle,
 }
@@ -1,8 +1,452 @@
+use crate::color::RGB;
+use crate::enums::LedMode;
+use crate::errors::FocusError;
+use crate::framer::Framer;
+use crate::{DEFAULT_BAUD_RATE, DEFAULT_BUFFER_CAPACITY, DEFAULT_READ_TIMEOUT};
+use js_sys::{Array, Function, Promise, Reflect, Uint8Array};
+use log::trace;
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    ReadableStream, ReadableStreamDefaultReader, SerialOptions, SerialPort, WritableStream,
+    WritableStreamDefaultWriter,
+};
 
+/// The Dygma Focus API, talking to the device over WebSerial.
+///
+/// Every call returns a promise, as the browser does not allow blocking.
 #[wasm_bindgen]
 #[derive(Debug)]
 pub struct Focus {
-    // TODO: serial
-    pub(crate) response_buffer: Vec<u8>,
+    pub(crate) port: Option<SerialPort>,
+    pub(crate) reader: ReadableStreamDefaultReader,
+    pub(crate) writer: WritableStreamDefaultWriter,
+    pub(crate) pending_read: Option<Promise>,
+    pub(crate) stale: bool,
+    pub(crate) framer: Framer,
+    pub(crate) command: String,
+    pub(crate) read_timeout: Duration,
+}
+
+/// Constructors
+#[wasm_bindgen]
+impl Focus {
+    /// Opens a port returned by `navigator.serial.requestPort()` and creates the Focus API.
+    ///
+    /// Default baud rate: 115,200
+    pub async fn open(port: SerialPort, baud_rate: Option<u32>) -> Result<Focus, JsError> {
+        let options = SerialOptions::new(baud_rate.unwrap_or(DEFAULT_BAUD_RATE));
+        JsFuture::from(port.open(&options))
+            .await
+            .map_err(web_serial_error)?;
+        let mut focus = Self::from_streams(port.readable(), port.writable())?;
+        focus.port = Some(port);
+        Ok(focus)
+    }
+
+    /// Creates the Focus API over a pair of streams, such as the ones of an already open port.
+    ///
+    /// Fails if the writable stream is already locked by another writer.
+    #[wasm_bindgen(js_name = fromStreams)]
+    pub fn from_streams(
+        readable: ReadableStream,
+        writable: WritableStream,
+    ) -> Result<Focus, JsError> {
+        Ok(Self {
+            port: None,
+            reader: readable.get_reader().unchecked_into(),
+            writer: writable.get_writer().map_err(web_serial_error)?,
+            pending_read: None,
+            stale: false,
+            framer: Framer::with_capacity(DEFAULT_BUFFER_CAPACITY),
+            command: String::new(),
+            read_timeout: DEFAULT_READ_TIMEOUT,
+        })
+    }
+
+    /// Releases the streams and closes the port, if it was opened by this instance.
+    pub async fn close(self) -> Result<(), JsError> {
+        if self.pending_read.is_some() {
+            let _ = JsFuture::from(self.reader.cancel()).await;
+        }
+        self.reader.release_lock();
+        self.writer.release_lock();
+        if let Some(port) = self.port {
+            JsFuture::from(port.close())
+                .await
+                .map_err(web_serial_error)?;
+        }
+        Ok(())
+    }
+
+    /// Sets how long the device may be silent while waiting for a response, in milliseconds.
+    #[wasm_bindgen(js_name = setReadTimeout)]
+    pub fn set_read_timeout(&mut self, milliseconds: u32) {
+        self.read_timeout = Duration::from_millis(milliseconds as u64);
+    }
+}
+
+/// Transport methods
+impl Focus {
+    /// Writes bytes to the port.
+    ///
+    /// What is left of a response that timed out is dropped first, so it is not taken as the response to this write.
+    pub async fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), FocusError> {
+        self.discard_stale().await?;
+        trace!("Writing bytes: {:02X?}", bytes);
+        let chunk = Uint8Array::from(bytes);
+        JsFuture::from(self.writer.write_with_chunk(&chunk))
+            .await
+            .map_err(web_serial_error)?;
+        Ok(())
+    }
+
+    /// Response from the port
+    ///
+    /// Waits until the response terminator arrives, or the port has been silent for the read timeout.
+    /// Bytes after the terminator are kept for the next call, as they belong to the next pipelined response.
+    pub async fn read_string(&mut self) -> Result<String, FocusError> {
+        self.read_response().await.map(str::to_string)
+    }
+
+    /// Response from the port, borrowed from the response buffer until the next read.
+    ///
+    /// See `read_string` for how long it waits.
+    pub async fn read_response(&mut self) -> Result<&str, FocusError> {
+        let frame = self.read_frame().await?;
+        std::str::from_utf8(frame).map_err(FocusError::Utf8ConversionError)
+    }
+
+    /// Reads until the framer holds a complete response, then takes it.
+    pub(crate) async fn read_frame(&mut self) -> Result<&[u8], FocusError> {
+        while !self.framer.has_frame() {
+            match self.read_chunk().await? {
+                Some(chunk) => {
+                    trace!("Received bytes: {:02X?}", &chunk);
+                    self.framer.push(&chunk);
+                }
+                None => {
+                    self.stale = true;
+                    return Err(FocusError::Timeout {
+                        command: self.command.clone(),
+                        elapsed: self.read_timeout,
+                    });
+                }
+            }
+        }
+        let frame = self.framer.next_frame().unwrap_or_default();
+        if !frame.is_empty() {
+            trace!("Command RX: {}", String::from_utf8_lossy(frame));
+        } else {
+            trace!("Command RX: [Ack]");
+        }
+        Ok(frame)
+    }
+
+    /// Reads the next chunk from the port, `None` when nothing arrives within the read timeout.
+    ///
+    /// A read that times out stays pending and is picked up again by the next call, as the reader can't take it back.
+    async fn read_chunk(&mut self) -> Result<Option<Vec<u8>>, FocusError> {
+        let read = self
+            .pending_read
+            .take()
+            .unwrap_or_else(|| self.reader.read());
+        let timeout = Sleep::new(self.read_timeout)?;
+        let race = Promise::race(&Array::of2(&read, &timeout.promise));
+        let result = JsFuture::from(race).await.map_err(web_serial_error)?;
+        if result.is_undefined() {
+            self.pending_read = Some(read);
+            return Ok(None);
+        }
+        let done = Reflect::get(&result, &"done".into())
+            .map_err(web_serial_error)?
+            .is_truthy();
+        if done {
+            return Err(FocusError::WebSerialError(
+                "the port was closed".to_string(),
+            ));
+        }
+        let value = Reflect::get(&result, &"value".into()).map_err(web_serial_error)?;
+        Ok(Some(Uint8Array::new(&value).to_vec()))
+    }
+
+    /// Drops the rest of a response that timed out, until the port has been silent for the read timeout.
+    async fn discard_stale(&mut self) -> Result<(), FocusError> {
+        if !self.stale {
+            return Ok(());
+        }
+        while let Some(chunk) = self.read_chunk().await? {
+            trace!("Dropping stale bytes: {:02X?}", &chunk);
+        }
+        self.stale = false;
+        self.framer.clear();
+        Ok(())
+    }
+}
+
+/// JavaScript bindings
+///
+/// Each forwards to the Focus API shared with the native targets.
+/// Commands without a binding here can be sent with `command`.
+#[wasm_bindgen]
+impl Focus {
+    /// Sends a command to the device and returns the raw response.
+    #[wasm_bindgen(js_name = command)]
+    pub async fn js_command(&mut self, command: &str) -> Result<String, JsError> {
+        Ok(self.query(command).await?)
+    }
+
+    /// Get the version of the firmware.
+    #[wasm_bindgen(js_name = version)]
+    pub async fn js_version(&mut self) -> Result<String, JsError> {
+        Ok(self.version().await?)
+    }
+
+    /// Gets the commands the firmware supports.
+    #[wasm_bindgen(js_name = help)]
+    pub async fn js_help(&mut self) -> Result<Vec<String>, JsError> {
+        Ok(self.help().await?)
+    }
+
+    /// Gets the whole custom keymap stored in the keyboard.
+    #[wasm_bindgen(js_name = keymapCustomGet)]
+    pub async fn js_keymap_custom_get(&mut self) -> Result<Vec<u16>, JsError> {
+        Ok(self.keymap_custom_get().await?)
+    }
+
+    /// Sets the whole custom keymap stored in the keyboard.
+    #[wasm_bindgen(js_name = keymapCustomSet)]
+    pub async fn js_keymap_custom_set(&mut self, data: Vec<u16>) -> Result<(), JsError> {
+        Ok(self.keymap_custom_set(&data).await?)
+    }
+
+    /// Gets the color map that assigns each color to each LED, per layer.
+    #[wasm_bindgen(js_name = colorMapGet)]
+    pub async fn js_color_map_get(&mut self) -> Result<Vec<u8>, JsError> {
+        Ok(self.color_map_get().await?)
+    }
+
+    /// Sets the color map that assigns each color to each LED, per layer.
+    #[wasm_bindgen(js_name = colorMapSet)]
+    pub async fn js_color_map_set(&mut self, data: Vec<u8>) -> Result<(), JsError> {
+        Ok(self.color_map_set(&data).await?)
+    }
+
+    /// Gets the Superkeys map.
+    #[wasm_bindgen(js_name = superkeysMapGet)]
+    pub async fn js_superkeys_map_get(&mut self) -> Result<Vec<u16>, JsError> {
+        Ok(self.superkeys_map_get().await?)
+    }
+
+    /// Gets the macros map.
+    #[wasm_bindgen(js_name = macrosMapGet)]
+    pub async fn js_macros_map_get(&mut self) -> Result<Vec<u8>, JsError> {
+        Ok(self.macros_map_get().await?)
+    }
+
+    /// Triggers a macro.
+    #[wasm_bindgen(js_name = macrosTrigger)]
+    pub async fn js_macros_trigger(&mut self, macro_id: u8) -> Result<(), JsError> {
+        Ok(self.macros_trigger(macro_id).await?)
+    }
+
+    /// Gets the LED mode, see `LedMode` for the values.
+    #[wasm_bindgen(js_name = ledModeGet)]
+    pub async fn js_led_mode_get(&mut self) -> Result<u8, JsError> {
+        Ok(self.led_mode_get().await? as u8)
+    }
+
+    /// Sets the LED mode, see `LedMode` for the values.
+    #[wasm_bindgen(js_name = ledModeSet)]
+    pub async fn js_led_mode_set(&mut self, mode: u8) -> Result<(), JsError> {
+        let mode = mode.to_string().parse::<LedMode>()?;
+        Ok(self.led_mode_set(mode).await?)
+    }
+
+    /// Gets the key LED brightness.
+    #[wasm_bindgen(js_name = ledBrightnessTopGet)]
+    pub async fn js_led_brightness_top_get(&mut self) -> Result<u8, JsError> {
+        Ok(self.led_brightness_top_get().await?)
+    }
+
+    /// Sets the key LED brightness.
+    #[wasm_bindgen(js_name = ledBrightnessTopSet)]
+    pub async fn js_led_brightness_top_set(&mut self, brightness: u8) -> Result<(), JsError> {
+        Ok(self.led_brightness_top_set(brightness).await?)
+    }
+
+    /// Sets all the LEDs to the provided color.
+    #[wasm_bindgen(js_name = ledAll)]
+    pub async fn js_led_all(&mut self, r: u8, g: u8, b: u8) -> Result<(), JsError> {
+        Ok(self.led_all(&RGB { r, g, b }).await?)
+    }
+
+    /// Switch to a certain layer.
+    #[wasm_bindgen(js_name = layerMoveTo)]
+    pub async fn js_layer_move_to(&mut self, layer: u8) -> Result<(), JsError> {
+        Ok(self.layer_move_to(layer).await?)
+    }
+
+    /// Gets the status for up to 32 layers, 1 for active and 0 for inactive.
+    #[wasm_bindgen(js_name = layerState)]
+    pub async fn js_layer_state(&mut self) -> Result<Vec<u8>, JsError> {
+        let state = self.layer_state().await?;
+        Ok(state.into_iter().map(u8::from).collect())
+    }
+
+    /// Gets the battery level of the left keyboard as a percentage.
+    #[wasm_bindgen(js_name = wirelessBatteryLevelLeftGet)]
+    pub async fn js_wireless_battery_level_left_get(&mut self) -> Result<u8, JsError> {
+        Ok(self.wireless_battery_level_left_get().await?)
+    }
+
+    /// Gets the battery level of the right keyboard as a percentage.
+    #[wasm_bindgen(js_name = wirelessBatteryLevelRightGet)]
+    pub async fn js_wireless_battery_level_right_get(&mut self) -> Result<u8, JsError> {
+        Ok(self.wireless_battery_level_right_get().await?)
+    }
+}
+
+/// A timer whose promise resolves to `undefined` after the duration.
+///
+/// The timer is cleared when dropped, so a read that wins the race doesn't leave it pending.
+struct Sleep {
+    promise: Promise,
+    handle: JsValue,
+}
+
+impl Sleep {
+    fn new(duration: Duration) -> Result<Self, FocusError> {
+        let set_timeout = global_function("setTimeout")?;
+        let milliseconds = JsValue::from(duration.as_millis() as f64);
+        let mut handle = JsValue::UNDEFINED;
+        let promise = Promise::new(&mut |resolve, _| {
+            handle = set_timeout
+                .call2(&JsValue::NULL, &resolve, &milliseconds)
+                .unwrap_or_default();
+        });
+        Ok(Self { promise, handle })
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let Ok(clear_timeout) = global_function("clearTimeout") {
+            let _ = clear_timeout.call1(&JsValue::NULL, &self.handle);
+        }
+    }
+}
+
+fn global_function(name: &str) -> Result<Function, FocusError> {
+    Reflect::get(&js_sys::global(), &name.into())
+        .map_err(web_serial_error)?
+        .dyn_into()
+        .map_err(web_serial_error)
+}
+
+fn web_serial_error(error: impl std::fmt::Debug) -> FocusError {
+    FocusError::WebSerialError(format!("{:?}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use wasm_bindgen_test::*;
+
+    /// A readable stream that yields the chunks, split to cross the terminator.
+    fn readable(chunks: &[&str]) -> ReadableStream {
+        let chunks = chunks
+            .iter()
+            .map(|chunk| Uint8Array::from(chunk.as_bytes()))
+            .collect::<Array>();
+        let start = Closure::once_into_js(move |controller: JsValue| {
+            let enqueue: Function = Reflect::get(&controller, &"enqueue".into())
+                .unwrap()
+                .unchecked_into();
+            for chunk in chunks.iter() {
+                enqueue.call1(&controller, &chunk).unwrap();
+            }
+        });
+        let source = js_sys::Object::new();
+        Reflect::set(&source, &"start".into(), &start).unwrap();
+        ReadableStream::new_with_underlying_source(&source).unwrap()
+    }
+
+    fn enqueue(controller: &JsValue, chunk: &str) {
+        let enqueue: Function = Reflect::get(controller, &"enqueue".into())
+            .unwrap()
+            .unchecked_into();
+        enqueue
+            .call1(controller, &Uint8Array::from(chunk.as_bytes()))
+            .unwrap();
+    }
+
+    /// Streams of a device that answers the first command after `delay` milliseconds, and the next ones right away.
+    fn late_device(delay: u32) -> (ReadableStream, WritableStream) {
+        let controller = Rc::new(RefCell::new(JsValue::UNDEFINED));
+        let start = Closure::once_into_js({
+            let controller = controller.clone();
+            move |value: JsValue| *controller.borrow_mut() = value
+        });
+        let source = js_sys::Object::new();
+        Reflect::set(&source, &"start".into(), &start).unwrap();
+        let readable = ReadableStream::new_with_underlying_source(&source).unwrap();
+
+        let mut writes = 0;
+        let write = Closure::<dyn FnMut(JsValue)>::new(move |_chunk: JsValue| {
+            writes += 1;
+            let controller = controller.borrow().clone();
+            if writes > 1 {
+                enqueue(&controller, &format!("answer {}\r\n.\r\n", writes));
+                return;
+            }
+            let answer = Closure::once_into_js(move || enqueue(&controller, "late\r\n.\r\n"));
+            global_function("setTimeout")
+                .unwrap()
+                .call2(&JsValue::NULL, &answer, &delay.into())
+                .unwrap();
+        })
+        .into_js_value();
+        let sink = js_sys::Object::new();
+        Reflect::set(&sink, &"write".into(), &write).unwrap();
+        let writable = WritableStream::new_with_underlying_sink(&sink).unwrap();
+        (readable, writable)
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_framing_across_chunks() {
+        let readable = readable(&["v1.2", ".3\r\n.", "\r\n", "0 1 0\r\n.\r\n"]);
+        let mut focus = Focus::from_streams(readable, WritableStream::new().unwrap()).unwrap();
+        assert_eq!("v1.2.3", focus.version().await.unwrap());
+        assert_eq!(vec![false, true, false], focus.layer_state().await.unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_read_timeout() {
+        let mut focus = Focus::from_streams(readable(&[]), WritableStream::new().unwrap()).unwrap();
+        focus.set_read_timeout(10);
+        let err = focus.query::<String>("version").await.unwrap_err();
+        assert!(matches!(err, FocusError::Timeout { .. }));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_late_response_is_dropped() {
+        let (readable, writable) = late_device(75);
+        let mut focus = Focus::from_streams(readable, writable).unwrap();
+        focus.set_read_timeout(50);
+
+        let err = focus.query::<String>("version").await.unwrap_err();
+        assert!(matches!(err, FocusError::Timeout { .. }));
+        assert_eq!("answer 2", focus.query::<String>("help").await.unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_locked_writable() {
+        let writable = WritableStream::new().unwrap();
+        let _writer = writable.get_writer().unwrap();
+        assert!(Focus::from_streams(readable(&[]), writable).is_err());
+    }
 }
@@ -1,8 +1,26 @@
+#[cfg(all(feature = "async", not(target_arch = "wasm32")))]
+pub use crate::asynchronous::{AsyncFocus, AsyncTransport};
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::builder::FocusBuilder;
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::cancel::CancelHandle;
 pub use crate::color::*;
+pub use crate::diagnostics::{HardwareDiagnostics, Sides};
 pub use crate::enums::*;
 pub use crate::errors::*;
 pub use crate::hardware::*;
+pub use crate::key::*;
+pub use crate::keymap::{Keymap, Position};
+pub use crate::layers::LayerEditor;
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::resilient::ResilientFocus;
+pub use crate::response::{FromFocusParts, FromFocusResponse};
 pub use crate::settings::*;
+pub use crate::superkeys::{Superkey, Superkeys};
+pub use crate::transport::simulator::VirtualFocus;
+pub use crate::transport::Transport;
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::watcher::{DeviceEvent, DeviceWatcher};
 
 #[cfg(unix)]
 pub use crate::platform::posix::Focus;
@@ -0,0 +1,298 @@
+use crate::builder::FocusBuilder;
+use crate::errors::FocusError;
+use crate::hardware::Device;
+use log::{debug, trace};
+use std::fmt::{Debug, Formatter};
+use std::thread;
+use std::time::Duration;
+
+#[cfg(unix)]
+use crate::platform::posix::Focus;
+#[cfg(windows)]
+use crate::platform::windows::Focus;
+
+/// A long-lived connection that follows a keyboard across unplugs and reboots.
+///
+/// The keyboard is identified by its USB vendor and product IDs, plus its serial number when it has one.
+/// When the connection is lost, the port is rediscovered and reopened with the same options.
+pub struct ResilientFocus {
+    device: Device,
+    connector: Connector,
+    backoff: Duration,
+    reconnect_attempts: u32,
+    focus: Option<Focus>,
+}
+
+/// Opens a new connection to the device, returning the device as it was found along with it.
+type Connector = Box<dyn FnMut(&Device) -> Result<(Device, Focus), FocusError> + Send>;
+
+impl Debug for ResilientFocus {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("ResilientFocus")
+            .field("device", &self.device)
+            .field("backoff", &self.backoff)
+            .field("reconnect_attempts", &self.reconnect_attempts)
+            .field("focus", &self.focus)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ResilientFocus {
+    /// Connects to the device with the default connection options.
+    pub fn new(device: &Device) -> Result<Self, FocusError> {
+        Self::with_builder(device, FocusBuilder::new())
+    }
+
+    /// Connects to the device, reusing the connection options of the builder for every reconnect.
+    ///
+    /// The port selection of the builder is replaced by the device.
+    pub fn with_builder(device: &Device, builder: FocusBuilder) -> Result<Self, FocusError> {
+        let focus = builder.clone().device(device).build()?;
+        let connector = move |device: &Device| {
+            let device = find(device)?;
+            let focus = builder.clone().device(&device).build()?;
+            Ok((device, focus))
+        };
+        Ok(Self::from_parts(device.clone(), focus, Box::new(connector)))
+    }
+
+    /// Connects to the device through the connector, which is called again for every reconnect.
+    ///
+    /// The connector gets the device as it was last seen, and returns it as it was found along with the connection.
+    /// This allows following a device over something other than a serial port, such as a simulated keyboard.
+    pub fn with_connector(
+        device: &Device,
+        mut connector: impl FnMut(&Device) -> Result<(Device, Focus), FocusError> + Send + 'static,
+    ) -> Result<Self, FocusError> {
+        let (device, focus) = connector(device)?;
+        Ok(Self::from_parts(device, focus, Box::new(connector)))
+    }
+
+    fn from_parts(device: Device, focus: Focus, connector: Connector) -> Self {
+        Self {
+            device,
+            connector,
+            backoff: Duration::from_millis(500),
+            reconnect_attempts: 10,
+            focus: Some(focus),
+        }
+    }
+
+    /// Sets how long to wait before each reconnect attempt.
+    ///
+    /// Default: 500 milliseconds
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sets how many times to look for the device before giving up on a reconnect.
+    ///
+    /// Default: 10
+    pub fn reconnect_attempts(mut self, attempts: u32) -> Self {
+        self.reconnect_attempts = attempts.max(1);
+        self
+    }
+
+    /// Gets the device, with the serial port it was last seen on.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// Whether there is an open connection, it may still turn out to be dead on the next command.
+    pub fn is_connected(&self) -> bool {
+        self.focus.is_some()
+    }
+
+    /// Gets the connection, reconnecting first if it was lost.
+    pub fn focus(&mut self) -> Result<&mut Focus, FocusError> {
+        if self.focus.is_none() {
+            self.reconnect()?;
+        }
+        self.focus
+            .as_mut()
+            .ok_or(FocusError::NoDevicesDetectedError)
+    }
+
+    /// Runs an idempotent command, retrying it once after reconnecting if the connection was lost.
+    ///
+    /// A command that timed out is not retried, the device may have applied it without answering in time.
+    /// The connection is still reopened on the next command.
+    pub fn run<T>(
+        &mut self,
+        mut command: impl FnMut(&mut Focus) -> Result<T, FocusError>,
+    ) -> Result<T, FocusError> {
+        match command(self.focus()?) {
+            Err(e) if e.is_connection_error() => {
+                self.focus = None;
+                if matches!(e, FocusError::Timeout { .. }) {
+                    return Err(e);
+                }
+                debug!("Connection lost, retrying after reconnect: {}", e);
+                command(self.focus()?)
+            }
+            result => result,
+        }
+    }
+
+    /// Runs a command without retrying it, the connection is reopened on the next command if it was lost.
+    pub fn run_once<T>(
+        &mut self,
+        command: impl FnOnce(&mut Focus) -> Result<T, FocusError>,
+    ) -> Result<T, FocusError> {
+        let result = command(self.focus()?);
+        if matches!(&result, Err(e) if e.is_connection_error()) {
+            self.focus = None;
+        }
+        result
+    }
+
+    /// Drops the current connection, then finds the device and opens it again.
+    pub fn reconnect(&mut self) -> Result<(), FocusError> {
+        self.focus = None;
+        let mut last_error = FocusError::DeviceNotFoundError {
+            selector: self.device.to_string(),
+        };
+        for attempt in 1..=self.reconnect_attempts {
+            thread::sleep(self.backoff);
+            trace!("Reconnect attempt {} for {}", attempt, self.device);
+            match (self.connector)(&self.device) {
+                Ok((device, focus)) => {
+                    self.device = device;
+                    self.focus = Some(focus);
+                    return Ok(());
+                }
+                Err(e) => last_error = e,
+            }
+        }
+        Err(last_error)
+    }
+}
+
+/// Finds the device among the connected ones.
+fn find(device: &Device) -> Result<Device, FocusError> {
+    let usb = device.hardware.usb;
+    Focus::find_all_devices()?
+        .into_iter()
+        .find(|found| {
+            found.hardware.usb.vendor_id == usb.vendor_id
+                && found.hardware.usb.product_id == usb.product_id
+                && (device.serial_number.is_none() || found.serial_number == device.serial_number)
+        })
+        .ok_or_else(|| FocusError::DeviceNotFoundError {
+            selector: device.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::LedMode;
+    use crate::hardware::types::hardware_virtual::DEFY_WIRED_VIRTUAL;
+    use crate::transport::simulator::VirtualFocus;
+    use crate::transport::Transport;
+    use std::io::{ErrorKind, Read, Write};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    enum Link {
+        Up,
+        Unplugged,
+        Silent,
+    }
+
+    /// A simulated keyboard behind a link that can be unplugged or go silent.
+    #[derive(Debug)]
+    struct Cable {
+        device: Arc<Mutex<VirtualFocus>>,
+        link: Arc<Mutex<Link>>,
+    }
+
+    impl Read for Cable {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match *self.link.lock().unwrap() {
+                Link::Up => self.device.lock().unwrap().read(buf),
+                Link::Unplugged => Err(ErrorKind::BrokenPipe.into()),
+                Link::Silent => Err(ErrorKind::TimedOut.into()),
+            }
+        }
+    }
+
+    impl Write for Cable {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            match *self.link.lock().unwrap() {
+                Link::Up => self.device.lock().unwrap().write(buf),
+                Link::Unplugged => Err(ErrorKind::BrokenPipe.into()),
+                Link::Silent => Ok(buf.len()),
+            }
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Transport for Cable {
+        fn timeout(&self) -> Duration {
+            self.device.lock().unwrap().timeout()
+        }
+
+        fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+            self.device.lock().unwrap().set_timeout(timeout)
+        }
+    }
+
+    /// Connects to the same simulated keyboard every time, plugging the link back in.
+    fn connect(link: &Arc<Mutex<Link>>, connects: &Arc<AtomicUsize>) -> ResilientFocus {
+        let device = Device {
+            hardware: DEFY_WIRED_VIRTUAL,
+            serial_port: "virtual".to_string(),
+            serial_number: None,
+        };
+        let keyboard = Arc::new(Mutex::new(VirtualFocus::new(&device.hardware).unwrap()));
+        let (link, connects) = (link.clone(), connects.clone());
+        ResilientFocus::with_connector(&device, move |device| {
+            connects.fetch_add(1, Ordering::SeqCst);
+            *link.lock().unwrap() = Link::Up;
+            let cable = Cable {
+                device: keyboard.clone(),
+                link: link.clone(),
+            };
+            Ok((device.clone(), Focus::new_via_transport(cable)))
+        })
+        .unwrap()
+        .backoff(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_resilient_reconnects_after_unplug() {
+        let link = Arc::new(Mutex::new(Link::Up));
+        let connects = Arc::new(AtomicUsize::new(0));
+        let mut focus = connect(&link, &connects);
+
+        *link.lock().unwrap() = Link::Unplugged;
+        let actual = focus.run(|focus| focus.hardware_version_get());
+
+        assert_eq!("Dygma Defy", actual.unwrap());
+        assert_eq!(2, connects.load(Ordering::SeqCst));
+        assert!(focus.is_connected());
+    }
+
+    #[test]
+    fn test_resilient_does_not_retry_timeout() {
+        let link = Arc::new(Mutex::new(Link::Up));
+        let connects = Arc::new(AtomicUsize::new(0));
+        let mut focus = connect(&link, &connects);
+        let mode = focus.run(|focus| focus.led_mode_get()).unwrap();
+
+        *link.lock().unwrap() = Link::Silent;
+        let actual = focus.run(|focus| focus.led_mode_set(LedMode::Rainbow));
+
+        assert!(matches!(actual, Err(FocusError::Timeout { .. })));
+        assert_eq!(1, connects.load(Ordering::SeqCst));
+        assert!(!focus.is_connected());
+        assert_eq!(mode, focus.run(|focus| focus.led_mode_get()).unwrap());
+        assert_eq!(2, connects.load(Ordering::SeqCst));
+    }
+}
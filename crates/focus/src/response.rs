@@ -0,0 +1,220 @@
+use crate::color::{RGB, RGBW};
+use crate::enums::{LedMode, WirelessPowerMode};
+use crate::errors::FocusError;
+
+/// Decodes the response of a Focus command into a typed value.
+///
+/// Implement this to query commands with a response format the crate does not know about.
+pub trait FromFocusResponse: Sized {
+    /// Decodes the trimmed response, without the terminator.
+    fn from_focus_response(response: &str) -> Result<Self, FocusError>;
+}
+
+/// A value made of a fixed number of whitespace separated parts, so it can be decoded from a list.
+pub trait FromFocusParts: Sized {
+    /// The number of parts that make up one value.
+    const PARTS: usize;
+
+    /// Decodes a value from exactly `PARTS` parts.
+    fn from_focus_parts(parts: &[&str]) -> Result<Self, FocusError>;
+}
+
+macro_rules! impl_numerical {
+    ($($t:ty),*) => {
+        $(
+            impl FromFocusResponse for $t {
+                fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+                    response
+                        .parse::<$t>()
+                        .map_err(|_| FocusError::ParseNumericalError {
+                            string: response.to_string(),
+                        })
+                }
+            }
+
+            impl FromFocusParts for $t {
+                const PARTS: usize = 1;
+
+                fn from_focus_parts(parts: &[&str]) -> Result<Self, FocusError> {
+                    parts[0]
+                        .parse::<$t>()
+                        .map_err(|e| FocusError::ParseNumericalVecError {
+                            string: e.to_string(),
+                        })
+                }
+            }
+        )*
+    };
+}
+
+impl_numerical!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+impl FromFocusResponse for bool {
+    fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+        match response {
+            "" => Err(FocusError::EmptyResponseError),
+            "0" | "false" => Ok(false),
+            "1" | "true" => Ok(true),
+            _ => Err(FocusError::ParseBoolError {
+                string: response.to_string(),
+            }),
+        }
+    }
+}
+
+impl FromFocusParts for bool {
+    const PARTS: usize = 1;
+
+    fn from_focus_parts(parts: &[&str]) -> Result<Self, FocusError> {
+        Self::from_focus_response(parts[0])
+    }
+}
+
+impl FromFocusResponse for String {
+    fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+        Ok(response.to_string())
+    }
+}
+
+impl FromFocusResponse for () {
+    fn from_focus_response(_response: &str) -> Result<Self, FocusError> {
+        Ok(())
+    }
+}
+
+impl FromFocusResponse for RGB {
+    fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+        if response.is_empty() {
+            return Err(FocusError::EmptyResponseError);
+        }
+        response.parse()
+    }
+}
+
+impl FromFocusParts for RGB {
+    const PARTS: usize = 3;
+
+    fn from_focus_parts(parts: &[&str]) -> Result<Self, FocusError> {
+        Ok(RGB {
+            r: parts[0].parse()?,
+            g: parts[1].parse()?,
+            b: parts[2].parse()?,
+        })
+    }
+}
+
+impl FromFocusResponse for RGBW {
+    fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+        if response.is_empty() {
+            return Err(FocusError::EmptyResponseError);
+        }
+        response.parse()
+    }
+}
+
+impl FromFocusParts for RGBW {
+    const PARTS: usize = 4;
+
+    fn from_focus_parts(parts: &[&str]) -> Result<Self, FocusError> {
+        Ok(RGBW {
+            r: parts[0].parse()?,
+            g: parts[1].parse()?,
+            b: parts[2].parse()?,
+            w: parts[3].parse()?,
+        })
+    }
+}
+
+impl FromFocusResponse for LedMode {
+    fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+        response
+            .parse()
+            .map_err(|_| FocusError::ParseNumericalError {
+                string: response.to_string(),
+            })
+    }
+}
+
+impl FromFocusResponse for WirelessPowerMode {
+    fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+        response
+            .parse()
+            .map_err(|_| FocusError::ParseNumericalError {
+                string: response.to_string(),
+            })
+    }
+}
+
+impl<T: FromFocusParts> FromFocusResponse for Vec<T> {
+    fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+        response
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .chunks(T::PARTS)
+            .map(|chunk| {
+                if chunk.len() != T::PARTS {
+                    return Err(FocusError::ChunkCountError {
+                        actual: chunk.len(),
+                        expected: T::PARTS,
+                    });
+                }
+                T::from_focus_parts(chunk)
+            })
+            .collect()
+    }
+}
+
+impl FromFocusResponse for Vec<String> {
+    fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+        Ok(response
+            .lines()
+            .map(|line| line.replace('\r', ""))
+            .collect())
+    }
+}
+
+impl<T: FromFocusResponse> FromFocusResponse for Option<T> {
+    fn from_focus_response(response: &str) -> Result<Self, FocusError> {
+        if response.is_empty() {
+            Ok(None)
+        } else {
+            T::from_focus_response(response).map(Some)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_scalars() {
+        assert_eq!(153, u8::from_focus_response("153").unwrap());
+        assert!(bool::from_focus_response("true").unwrap());
+        assert_eq!(LedMode::Rainbow, LedMode::from_focus_response("1").unwrap());
+        assert_eq!(None, Option::<u16>::from_focus_response("").unwrap());
+    }
+
+    #[test]
+    fn test_decode_vecs() {
+        let expected = vec![RGBW {
+            r: 1,
+            g: 2,
+            b: 3,
+            w: 4,
+        }];
+        assert_eq!(
+            expected,
+            Vec::<RGBW>::from_focus_response("1 2 3 4").unwrap()
+        );
+        assert_eq!(
+            vec![true, false],
+            Vec::<bool>::from_focus_response("1 0").unwrap()
+        );
+        assert_eq!(
+            vec!["version", "help"],
+            Vec::<String>::from_focus_response("version\r\nhelp").unwrap()
+        );
+        assert!(Vec::<RGB>::from_focus_response("1 2 3 4").is_err());
+    }
+}
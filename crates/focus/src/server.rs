@@ -0,0 +1,495 @@
+use crate::color::{RGB, RGBW};
+use crate::enums::{LedMode, WirelessPowerMode};
+use crate::errors::FocusError;
+use crate::settings::Settings;
+use log::{debug, trace};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+#[cfg(unix)]
+use crate::platform::posix::Focus;
+#[cfg(windows)]
+use crate::platform::windows::Focus;
+
+/// The address the server listens on by default, only reachable from this machine.
+pub const DEFAULT_SERVER_ADDRESS: &str = "127.0.0.1:8765";
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const DEVICE_ERROR: i64 = -32000;
+
+/// An embeddable JSON-RPC 2.0 server exposing the Focus API over HTTP.
+///
+/// Requests are `POST`ed as `application/json`, for example `{"jsonrpc": "2.0", "id": 1, "method": "layer.moveTo", "params": {"layer": 2}}`.
+/// Discovery works without a device, every other method needs one, either passed in or opened with `connect`.
+/// Requests from a web page of another site are refused, so a browser can't be used to reach the keyboard.
+///
+/// Methods: `devices`, `connect`, `version`, `settings.get`, `settings.set`, `setting.get`, `setting.set`,
+/// `led.mode.get`, `led.mode.set`, `led.brightness.get`, `led.brightness.set`, `led.at.get`, `led.at.set`, `led.all`,
+/// `layer.state`, `layer.isActive`, `layer.activate`, `layer.deactivate`, `layer.moveTo` and `battery`.
+/// The names `setting.get` and `setting.set` take are the snake case `Settings` field names, whatever the serde features.
+pub struct FocusServer {
+    server: Server,
+    focus: Option<Focus>,
+}
+
+/// The battery levels and statuses of both sides of a wireless keyboard.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "serde_camel_case", serde(rename_all = "camelCase"))]
+pub struct Battery {
+    pub level_left: u8,
+    pub level_right: u8,
+    pub status_left: u8,
+    pub status_right: u8,
+}
+
+/// A JSON-RPC error object.
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl ToString) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<FocusError> for RpcError {
+    fn from(e: FocusError) -> Self {
+        Self::new(DEVICE_ERROR, e)
+    }
+}
+
+impl FocusServer {
+    /// Starts listening, serving the device when one is provided.
+    pub fn bind(address: impl ToSocketAddrs, focus: Option<Focus>) -> Result<Self, FocusError> {
+        let server = Server::http(address).map_err(FocusError::ServerError)?;
+        Ok(Self { server, focus })
+    }
+
+    /// Gets the address the server is listening on.
+    pub fn address(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// Serves requests one at a time, until the listener fails.
+    pub fn run(mut self) -> Result<(), FocusError> {
+        loop {
+            let request = self
+                .server
+                .recv()
+                .map_err(|e| FocusError::ServerError(e.into()))?;
+            self.handle(request);
+        }
+    }
+
+    fn handle(&mut self, mut request: Request) {
+        let response = if *request.method() != Method::Post {
+            Response::from_string("").with_status_code(405)
+        } else if let Err(status) = check_headers(&request) {
+            Response::from_string("").with_status_code(status)
+        } else {
+            let mut body = String::new();
+            let reply = match request.as_reader().read_to_string(&mut body) {
+                Ok(_) => self.reply(&body),
+                Err(e) => error_reply(Value::Null, RpcError::new(PARSE_ERROR, e)),
+            };
+            Response::from_string(reply.to_string()).with_header(
+                Header::from_bytes("Content-Type", "application/json").expect("valid header"),
+            )
+        };
+        if let Err(e) = request.respond(response) {
+            debug!("Failed to respond: {}", e);
+        }
+    }
+
+    /// Answers a single JSON-RPC request body.
+    fn reply(&mut self, body: &str) -> Value {
+        trace!("RPC RX: {}", body);
+        let request = match serde_json::from_str::<Value>(body) {
+            Ok(request) => request,
+            Err(e) => return error_reply(Value::Null, RpcError::new(PARSE_ERROR, e)),
+        };
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let Some(method) = request.get("method").and_then(Value::as_str) else {
+            return error_reply(id, RpcError::new(INVALID_REQUEST, "method is missing"));
+        };
+        let params = request.get("params").cloned().unwrap_or(Value::Null);
+        match self.call(method, &params) {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(e) => error_reply(id, e),
+        }
+    }
+
+    fn call(&mut self, method: &str, params: &Value) -> Result<Value, RpcError> {
+        match method {
+            "devices" => to_value(Focus::find_all_devices()?),
+            "connect" => {
+                self.focus = Some(Focus::new_via_port(&param::<String>(params, "port")?)?);
+                Ok(Value::Null)
+            }
+            "version" => to_value(self.focus()?.version()?),
+            "settings.get" => to_value(self.focus()?.settings_get()?),
+            "settings.set" => {
+                let settings = serde_json::from_value::<Settings>(params.clone())
+                    .map_err(|e| RpcError::new(INVALID_PARAMS, e))?;
+                self.focus()?.settings_set(&settings)?;
+                Ok(Value::Null)
+            }
+            "setting.get" => {
+                let name = param::<String>(params, "name")?;
+                setting_get(self.focus()?, &name)
+            }
+            "setting.set" => {
+                let name = param::<String>(params, "name")?;
+                setting_set(self.focus()?, &name, params)?;
+                Ok(Value::Null)
+            }
+            "led.mode.get" => to_value(self.focus()?.led_mode_get()?),
+            "led.mode.set" => {
+                let mode = param::<LedMode>(params, "mode")?;
+                self.focus()?.led_mode_set(mode)?;
+                Ok(Value::Null)
+            }
+            "led.brightness.get" => to_value(self.focus()?.led_brightness_top_get()?),
+            "led.brightness.set" => {
+                let brightness = param::<u8>(params, "brightness")?;
+                self.focus()?.led_brightness_top_set(brightness)?;
+                Ok(Value::Null)
+            }
+            "led.at.get" => {
+                let led = param::<u8>(params, "led")?;
+                to_value(self.focus()?.led_at_get(led)?)
+            }
+            "led.at.set" => {
+                let led = param::<u8>(params, "led")?;
+                let color = param::<RGB>(params, "color")?;
+                self.focus()?.led_at_set(led, &color)?;
+                Ok(Value::Null)
+            }
+            "led.all" => {
+                let color = param::<RGB>(params, "color")?;
+                self.focus()?.led_all(&color)?;
+                Ok(Value::Null)
+            }
+            "layer.state" => to_value(self.focus()?.layer_state()?),
+            "layer.isActive" => {
+                let layer = param::<u8>(params, "layer")?;
+                to_value(self.focus()?.layer_is_active(layer)?)
+            }
+            "layer.activate" => {
+                let layer = param::<u8>(params, "layer")?;
+                self.focus()?.layer_activate(layer)?;
+                Ok(Value::Null)
+            }
+            "layer.deactivate" => {
+                let layer = param::<Option<u8>>(params, "layer")?;
+                self.focus()?.layer_deactivate(layer)?;
+                Ok(Value::Null)
+            }
+            "layer.moveTo" => {
+                let layer = param::<u8>(params, "layer")?;
+                self.focus()?.layer_move_to(layer)?;
+                Ok(Value::Null)
+            }
+            "battery" => {
+                let focus = self.focus()?;
+                to_value(Battery {
+                    level_left: focus.wireless_battery_level_left_get()?,
+                    level_right: focus.wireless_battery_level_right_get()?,
+                    status_left: focus.wireless_battery_status_left_get()?,
+                    status_right: focus.wireless_battery_status_right_get()?,
+                })
+            }
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("unknown method: {}", method),
+            )),
+        }
+    }
+
+    fn focus(&mut self) -> Result<&mut Focus, RpcError> {
+        self.focus
+            .as_mut()
+            .ok_or_else(|| RpcError::new(DEVICE_ERROR, "no device connected, call connect first"))
+    }
+}
+
+/// Gets a named parameter, a missing one is read as `null` so optional parameters can be left out.
+fn param<T: DeserializeOwned>(params: &Value, name: &str) -> Result<T, RpcError> {
+    let value = params.get(name).cloned().unwrap_or(Value::Null);
+    serde_json::from_value(value)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("{}: {}", name, e)))
+}
+
+fn to_value(value: impl Serialize) -> Result<Value, RpcError> {
+    serde_json::to_value(value).map_err(|e| RpcError::new(DEVICE_ERROR, e))
+}
+
+/// Reads a single setting with its own getter, by its `Settings` field name.
+fn setting_get(focus: &mut Focus, name: &str) -> Result<Value, RpcError> {
+    match name {
+        "keymap_custom" => to_value(focus.keymap_custom_get()?),
+        "keymap_default" => to_value(focus.keymap_default_get()?),
+        "keymap_only_custom" => to_value(focus.keymap_only_custom_get()?),
+        "settings_default_layer" => to_value(focus.settings_default_layer_get()?),
+        "superkeys_map" => to_value(focus.superkeys_map_get()?),
+        "superkeys_wait_for" => to_value(focus.superkeys_wait_for_get()?),
+        "superkeys_timeout" => to_value(focus.superkeys_timeout_get()?),
+        "superkeys_repeat" => to_value(focus.superkeys_repeat_get()?),
+        "superkeys_hold_start" => to_value(focus.superkeys_hold_start_get()?),
+        "superkeys_overlap" => to_value(focus.superkeys_overlap_get()?),
+        "led_mode" => to_value(focus.led_mode_get()?),
+        "led_brightness_keys_wired" => to_value(focus.led_brightness_top_get()?),
+        "led_brightness_underglow_wired" => to_value(focus.led_brightness_underglow_wired_get()?),
+        "led_brightness_keys_wireless" => to_value(focus.led_brightness_keys_wireless_get()?),
+        "led_brightness_underglow_wireless" => {
+            to_value(focus.led_brightness_underglow_wireless_get()?)
+        }
+        "led_fade" => to_value(focus.led_fade_get()?),
+        "led_theme" => to_value(focus.led_theme_get()?),
+        "palette_rgb" => to_value(focus.palette_rgb_get()?),
+        "palette_rgbw" => to_value(focus.palette_rgbw_get()?),
+        "color_map" => to_value(focus.color_map_get()?),
+        "led_idle_true_sleep" => to_value(focus.led_idle_true_sleep_get()?),
+        "led_idle_true_sleep_time" => to_value(focus.led_idle_true_sleep_time_get()?),
+        "led_idle_time_limit_wired" => to_value(focus.led_idle_time_limit_wired_get()?),
+        "led_idle_time_limit_wireless" => to_value(focus.led_idle_time_limit_wireless_get()?),
+        "qukeys_hold_timeout" => to_value(focus.qukeys_hold_timeout_get()?),
+        "qukeys_overlap_threshold" => to_value(focus.qukeys_overlap_threshold_get()?),
+        "macros_map" => to_value(focus.macros_map_get()?),
+        "mouse_speed" => to_value(focus.mouse_speed_get()?),
+        "mouse_delay" => to_value(focus.mouse_delay_get()?),
+        "mouse_acceleration_speed" => to_value(focus.mouse_acceleration_speed_get()?),
+        "mouse_acceleration_delay" => to_value(focus.mouse_acceleration_delay_get()?),
+        "mouse_wheel_speed" => to_value(focus.mouse_wheel_speed_get()?),
+        "mouse_wheel_delay" => to_value(focus.mouse_wheel_delay_get()?),
+        "mouse_speed_limit" => to_value(focus.mouse_speed_limit_get()?),
+        "wireless_battery_saving_mode" => to_value(focus.wireless_battery_saving_mode_get()?),
+        "wireless_rf_power_level" => to_value(focus.wireless_rf_power_level_get()?),
+        "wireless_rf_channel_hop" => to_value(focus.wireless_rf_channel_hop_get()?),
+        _ => Err(unknown_setting(name)),
+    }
+}
+
+/// Writes a single setting with its own setter, by its `Settings` field name.
+fn setting_set(focus: &mut Focus, name: &str, params: &Value) -> Result<(), RpcError> {
+    match name {
+        "keymap_custom" => focus.keymap_custom_set(&param::<Vec<u16>>(params, "value")?)?,
+        "keymap_default" => focus.keymap_default_set(&param::<Vec<u16>>(params, "value")?)?,
+        "keymap_only_custom" => focus.keymap_only_custom_set(param(params, "value")?)?,
+        "settings_default_layer" => focus.settings_default_layer_set(param(params, "value")?)?,
+        "superkeys_map" => focus.superkeys_map_set(&param::<Vec<u16>>(params, "value")?)?,
+        "superkeys_wait_for" => focus.superkeys_wait_for_set(param(params, "value")?)?,
+        "superkeys_timeout" => focus.superkeys_timeout_set(param(params, "value")?)?,
+        "superkeys_repeat" => focus.superkeys_repeat_set(param(params, "value")?)?,
+        "superkeys_hold_start" => focus.superkeys_hold_start_set(param(params, "value")?)?,
+        "superkeys_overlap" => focus.superkeys_overlap_set(param(params, "value")?)?,
+        "led_mode" => focus.led_mode_set(param::<LedMode>(params, "value")?)?,
+        "led_brightness_keys_wired" => focus.led_brightness_top_set(param(params, "value")?)?,
+        "led_brightness_underglow_wired" => {
+            focus.led_brightness_underglow_wired_set(param(params, "value")?)?
+        }
+        "led_brightness_keys_wireless" => {
+            focus.led_brightness_keys_wireless_set(param(params, "value")?)?
+        }
+        "led_brightness_underglow_wireless" => {
+            focus.led_brightness_underglow_wireless_set(param(params, "value")?)?
+        }
+        "led_fade" => focus.led_fade_set(param(params, "value")?)?,
+        "led_theme" => focus.led_theme_set(&param::<Vec<RGB>>(params, "value")?)?,
+        "palette_rgb" => focus.palette_rgb_set(&param::<Vec<RGB>>(params, "value")?)?,
+        "palette_rgbw" => focus.palette_rgbw_set(&param::<Vec<RGBW>>(params, "value")?)?,
+        "color_map" => focus.color_map_set(&param::<Vec<u8>>(params, "value")?)?,
+        "led_idle_true_sleep" => focus.led_idle_true_sleep_set(param(params, "value")?)?,
+        "led_idle_true_sleep_time" => {
+            focus.led_idle_true_sleep_time_set(param(params, "value")?)?
+        }
+        "led_idle_time_limit_wired" => {
+            focus.led_idle_time_limit_wired_set(param(params, "value")?)?
+        }
+        "led_idle_time_limit_wireless" => {
+            focus.led_idle_time_limit_wireless_set(param(params, "value")?)?
+        }
+        "qukeys_hold_timeout" => focus.qukeys_hold_timeout_set(param(params, "value")?)?,
+        "qukeys_overlap_threshold" => {
+            focus.qukeys_overlap_threshold_set(param(params, "value")?)?
+        }
+        "macros_map" => focus.macros_map_set(&param::<Vec<u8>>(params, "value")?)?,
+        "mouse_speed" => focus.mouse_speed_set(param(params, "value")?)?,
+        "mouse_delay" => focus.mouse_delay_set(param(params, "value")?)?,
+        "mouse_acceleration_speed" => {
+            focus.mouse_acceleration_speed_set(param(params, "value")?)?
+        }
+        "mouse_acceleration_delay" => {
+            focus.mouse_acceleration_delay_set(param(params, "value")?)?
+        }
+        "mouse_wheel_speed" => focus.mouse_wheel_speed_set(param(params, "value")?)?,
+        "mouse_wheel_delay" => focus.mouse_wheel_delay_set(param(params, "value")?)?,
+        "mouse_speed_limit" => focus.mouse_speed_limit_set(param(params, "value")?)?,
+        "wireless_battery_saving_mode" => {
+            focus.wireless_battery_saving_mode_set(param(params, "value")?)?
+        }
+        "wireless_rf_power_level" => {
+            focus.wireless_rf_power_level_set(param::<WirelessPowerMode>(params, "value")?)?
+        }
+        "wireless_rf_channel_hop" => focus.wireless_rf_channel_hop_set(param(params, "value")?)?,
+        _ => return Err(unknown_setting(name)),
+    }
+    Ok(())
+}
+
+fn unknown_setting(name: &str) -> RpcError {
+    RpcError::new(INVALID_PARAMS, format!("unknown setting: {}", name))
+}
+
+/// Refuses requests a web page of another site could have made, answering with the status code to send.
+///
+/// A cross site page can't set `Content-Type: application/json` without a preflight, which is refused,
+/// its `Origin` names the other site, and a DNS rebinding attack shows up as a host name other than `localhost`.
+fn check_headers(request: &Request) -> Result<(), u16> {
+    let header = |name: &'static str| {
+        request
+            .headers()
+            .iter()
+            .find(|header| header.field.equiv(name))
+            .map(|header| header.value.as_str())
+    };
+    let content_type = header("Content-Type").unwrap_or_default();
+    let media_type = content_type.split(';').next().unwrap_or_default().trim();
+    if !media_type.eq_ignore_ascii_case("application/json") {
+        return Err(415);
+    }
+    let host = header("Host")
+        .filter(|host| is_local_host(host))
+        .ok_or(403u16)?;
+    match header("Origin") {
+        Some(origin) if origin.split_once("://").map(|(_, rest)| rest) != Some(host) => Err(403),
+        _ => Ok(()),
+    }
+}
+
+/// Whether the `Host` header is `localhost` or an IP address, with or without a port.
+fn is_local_host(host: &str) -> bool {
+    let name = match host.rsplit_once(':') {
+        Some((name, port)) if port.parse::<u16>().is_ok() => name,
+        _ => host,
+    };
+    name.eq_ignore_ascii_case("localhost")
+        || name
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .is_ok()
+}
+
+fn error_reply(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_virtual::DEFY_WIRELESS_VIRTUAL;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    const JSON_HEADERS: &str = "Host: localhost\r\nContent-Type: application/json\r\n";
+
+    /// Posts the body with the headers, returning the status code and the response body.
+    fn post(address: SocketAddr, headers: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "POST / HTTP/1.1\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            headers,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, body.to_string())
+    }
+
+    fn rpc(address: SocketAddr, body: &str) -> Value {
+        let (_, body) = post(address, JSON_HEADERS, body);
+        serde_json::from_str(&body).unwrap()
+    }
+
+    fn serve() -> SocketAddr {
+        let focus = Focus::new_virtual(&DEFY_WIRELESS_VIRTUAL).unwrap();
+        let server = FocusServer::bind("127.0.0.1:0", Some(focus)).unwrap();
+        let address = server.address().unwrap();
+        std::thread::spawn(move || server.run());
+        address
+    }
+
+    #[test]
+    fn test_server_rpc() {
+        let address = serve();
+
+        let set = rpc(
+            address,
+            r#"{"jsonrpc":"2.0","id":1,"method":"setting.set","params":{"name":"mouse_speed","value":100}}"#,
+        );
+        let get = rpc(
+            address,
+            r#"{"jsonrpc":"2.0","id":2,"method":"setting.get","params":{"name":"mouse_speed"}}"#,
+        );
+        let unknown = rpc(
+            address,
+            r#"{"jsonrpc":"2.0","id":3,"method":"setting.get","params":{"name":"mouseSpeed"}}"#,
+        );
+        let invalid = rpc(
+            address,
+            r#"{"jsonrpc":"2.0","id":4,"method":"layer.moveTo","params":{"layer":"top"}}"#,
+        );
+
+        assert_eq!(Value::Null, set["result"]);
+        assert_eq!(json!(100), get["result"]);
+        assert_eq!(json!(INVALID_PARAMS), unknown["error"]["code"]);
+        assert_eq!(json!(INVALID_PARAMS), invalid["error"]["code"]);
+    }
+
+    #[test]
+    fn test_server_refuses_cross_site_requests() {
+        let address = serve();
+        let body = r#"{"jsonrpc":"2.0","id":1,"method":"version"}"#;
+        let status = |headers: &str| post(address, headers, body).0;
+
+        assert_eq!(200, status(JSON_HEADERS));
+        assert_eq!(
+            200,
+            status(&format!("Origin: http://localhost\r\n{}", JSON_HEADERS))
+        );
+        assert_eq!(
+            415,
+            status("Host: localhost\r\nContent-Type: text/plain\r\n")
+        );
+        assert_eq!(
+            403,
+            status(&format!("Origin: http://example.com\r\n{}", JSON_HEADERS))
+        );
+        assert_eq!(
+            403,
+            status("Host: example.com\r\nContent-Type: application/json\r\n")
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use crate::color::*;
+use crate::commands;
 use crate::enums::{LedMode, WirelessPowerMode};
+use crate::errors::FocusError;
+use crate::response::FromFocusResponse;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -46,3 +49,207 @@ pub struct Settings {
     pub wireless_rf_power_level: Option<WirelessPowerMode>,
     pub wireless_rf_channel_hop: Option<bool>,
 }
+
+/// The commands that read every setting, in the order `Settings::from_responses` expects them.
+pub(crate) const SETTINGS_GET_COMMANDS: [&str; 36] = [
+    "keymap.custom",
+    "keymap.default",
+    "keymap.onlyCustom",
+    "settings.defaultLayer",
+    "superkeys.map",
+    "superkeys.waitfor",
+    "superkeys.timeout",
+    "superkeys.repeat",
+    "superkeys.holdstart",
+    "superkeys.overlap",
+    "led.mode",
+    "led.brightness",
+    "led.brightnessUG",
+    "led.brightness.wireless",
+    "led.brightnessUG.wireless",
+    "led.fade",
+    "led.theme",
+    "palette",
+    "colormap.map",
+    "idleleds.true_sleep",
+    "idleleds.true_sleep_time",
+    "idleleds.time_limit",
+    "idleleds.wireless",
+    "qukeys.holdTimeout",
+    "qukeys.overlapThreshold",
+    "macros.map",
+    "mouse.speed",
+    "mouse.speedDelay",
+    "mouse.accelSpeed",
+    "mouse.accelDelay",
+    "mouse.wheelSpeed",
+    "mouse.wheelDelay",
+    "mouse.speedLimit",
+    "wireless.battery.savingMode",
+    "wireless.rf.power",
+    "wireless.rf.channelHop",
+];
+
+impl Settings {
+    /// Decodes the responses to `SETTINGS_GET_COMMANDS`.
+    ///
+    /// Settings that not every device supports are `None` when their response can't be decoded.
+    pub(crate) fn from_responses(responses: Vec<String>) -> Result<Self, FocusError> {
+        let [keymap_custom, keymap_default, keymap_only_custom, settings_default_layer, superkeys_map, superkeys_wait_for, superkeys_timeout, superkeys_repeat, superkeys_hold_start, superkeys_overlap, led_mode, led_brightness_keys_wired, led_brightness_underglow_wired, led_brightness_keys_wireless, led_brightness_underglow_wireless, led_fade, led_theme, palette, color_map, led_idle_true_sleep, led_idle_true_sleep_time, led_idle_time_limit_wired, led_idle_time_limit_wireless, qukeys_hold_timeout, qukeys_overlap_threshold, macros_map, mouse_speed, mouse_delay, mouse_acceleration_speed, mouse_acceleration_delay, mouse_wheel_speed, mouse_wheel_delay, mouse_speed_limit, wireless_battery_saving_mode, wireless_rf_power_level, wireless_rf_channel_hop] =
+            <[String; SETTINGS_GET_COMMANDS.len()]>::try_from(responses).map_err(|responses| {
+                FocusError::ChunkCountError {
+                    actual: responses.len(),
+                    expected: SETTINGS_GET_COMMANDS.len(),
+                }
+            })?;
+
+        Ok(Settings {
+            keymap_custom: FromFocusResponse::from_focus_response(&keymap_custom)?,
+            keymap_default: FromFocusResponse::from_focus_response(&keymap_default)?,
+            keymap_only_custom: FromFocusResponse::from_focus_response(&keymap_only_custom)?,
+            settings_default_layer: FromFocusResponse::from_focus_response(
+                &settings_default_layer,
+            )?,
+            superkeys_map: FromFocusResponse::from_focus_response(&superkeys_map)?,
+            superkeys_wait_for: FromFocusResponse::from_focus_response(&superkeys_wait_for)?,
+            superkeys_timeout: FromFocusResponse::from_focus_response(&superkeys_timeout)?,
+            superkeys_repeat: FromFocusResponse::from_focus_response(&superkeys_repeat)?,
+            superkeys_hold_start: FromFocusResponse::from_focus_response(&superkeys_hold_start)?,
+            superkeys_overlap: FromFocusResponse::from_focus_response(&superkeys_overlap)?,
+            led_mode: FromFocusResponse::from_focus_response(&led_mode)?,
+            led_brightness_keys_wired: FromFocusResponse::from_focus_response(
+                &led_brightness_keys_wired,
+            )?,
+            led_brightness_underglow_wired: FromFocusResponse::from_focus_response(
+                &led_brightness_underglow_wired,
+            )
+            .ok(),
+            led_brightness_keys_wireless: FromFocusResponse::from_focus_response(
+                &led_brightness_keys_wireless,
+            )
+            .ok(),
+            led_brightness_underglow_wireless: FromFocusResponse::from_focus_response(
+                &led_brightness_underglow_wireless,
+            )
+            .ok(),
+            led_fade: FromFocusResponse::from_focus_response(&led_fade).ok(),
+            led_theme: FromFocusResponse::from_focus_response(&led_theme)?,
+            palette_rgb: FromFocusResponse::from_focus_response(&palette).ok(),
+            palette_rgbw: FromFocusResponse::from_focus_response(&palette).ok(),
+            color_map: FromFocusResponse::from_focus_response(&color_map)?,
+            led_idle_true_sleep: FromFocusResponse::from_focus_response(&led_idle_true_sleep).ok(),
+            led_idle_true_sleep_time: FromFocusResponse::from_focus_response(
+                &led_idle_true_sleep_time,
+            )
+            .ok(),
+            led_idle_time_limit_wired: FromFocusResponse::from_focus_response(
+                &led_idle_time_limit_wired,
+            )?,
+            led_idle_time_limit_wireless: FromFocusResponse::from_focus_response(
+                &led_idle_time_limit_wireless,
+            )
+            .ok(),
+            qukeys_hold_timeout: FromFocusResponse::from_focus_response(&qukeys_hold_timeout)?,
+            qukeys_overlap_threshold: FromFocusResponse::from_focus_response(
+                &qukeys_overlap_threshold,
+            )?,
+            macros_map: FromFocusResponse::from_focus_response(&macros_map)?,
+            mouse_speed: FromFocusResponse::from_focus_response(&mouse_speed)?,
+            mouse_delay: FromFocusResponse::from_focus_response(&mouse_delay)?,
+            mouse_acceleration_speed: FromFocusResponse::from_focus_response(
+                &mouse_acceleration_speed,
+            )?,
+            mouse_acceleration_delay: FromFocusResponse::from_focus_response(
+                &mouse_acceleration_delay,
+            )?,
+            mouse_wheel_speed: FromFocusResponse::from_focus_response(&mouse_wheel_speed)?,
+            mouse_wheel_delay: FromFocusResponse::from_focus_response(&mouse_wheel_delay)?,
+            mouse_speed_limit: FromFocusResponse::from_focus_response(&mouse_speed_limit)?,
+            wireless_battery_saving_mode: FromFocusResponse::from_focus_response(
+                &wireless_battery_saving_mode,
+            )
+            .ok(),
+            wireless_rf_power_level: FromFocusResponse::from_focus_response(
+                &wireless_rf_power_level,
+            )
+            .ok(),
+            wireless_rf_channel_hop: FromFocusResponse::from_focus_response(
+                &wireless_rf_channel_hop,
+            )
+            .ok(),
+        })
+    }
+
+    /// Builds the commands that write every setting, validating the values first so nothing is sent when one is out of range.
+    pub(crate) fn to_commands(&self) -> Result<Vec<String>, FocusError> {
+        let mut commands = vec![
+            commands::keymap_custom(&self.keymap_custom),
+            commands::keymap_default(&self.keymap_default),
+            commands::keymap_only_custom(self.keymap_only_custom),
+            commands::settings_default_layer(self.settings_default_layer)?,
+            commands::superkeys_map(&self.superkeys_map),
+            commands::superkeys_wait_for(self.superkeys_wait_for),
+            commands::superkeys_timeout(self.superkeys_timeout),
+            commands::superkeys_repeat(self.superkeys_repeat),
+            commands::superkeys_hold_start(self.superkeys_hold_start),
+            commands::superkeys_overlap(self.superkeys_overlap)?,
+            commands::led_mode(self.led_mode),
+            commands::led_brightness_top(self.led_brightness_keys_wired),
+        ];
+        if let Some(value) = self.led_brightness_underglow_wired {
+            commands.push(commands::led_brightness_underglow_wired(value));
+        }
+        if let Some(value) = self.led_brightness_keys_wireless {
+            commands.push(commands::led_brightness_keys_wireless(value));
+        }
+        if let Some(value) = self.led_brightness_underglow_wireless {
+            commands.push(commands::led_brightness_underglow_wireless(value));
+        }
+        if let Some(value) = self.led_fade {
+            commands.push(commands::led_fade(value));
+        }
+        commands.push(commands::led_theme(&self.led_theme));
+        if let Some(value) = &self.palette_rgb {
+            commands.push(commands::palette_rgb(value));
+        }
+        if let Some(value) = &self.palette_rgbw {
+            commands.push(commands::palette_rgbw(value));
+        }
+        commands.push(commands::color_map(&self.color_map));
+        if let Some(value) = self.led_idle_true_sleep {
+            commands.push(commands::led_idle_true_sleep(value));
+        }
+        if let Some(value) = self.led_idle_true_sleep_time {
+            commands.push(commands::led_idle_true_sleep_time(value)?);
+        }
+        commands.push(commands::led_idle_time_limit_wired(
+            self.led_idle_time_limit_wired,
+        )?);
+        if let Some(value) = self.led_idle_time_limit_wireless {
+            commands.push(commands::led_idle_time_limit_wireless(value)?);
+        }
+        commands.extend([
+            commands::qukeys_hold_timeout(self.qukeys_hold_timeout),
+            commands::qukeys_overlap_threshold(self.qukeys_overlap_threshold),
+            commands::macros_map(&self.macros_map),
+            commands::mouse_speed(self.mouse_speed)?,
+            commands::mouse_delay(self.mouse_delay),
+            commands::mouse_acceleration_speed(self.mouse_acceleration_speed),
+            commands::mouse_acceleration_delay(self.mouse_acceleration_delay),
+            commands::mouse_wheel_speed(self.mouse_wheel_speed),
+            commands::mouse_wheel_delay(self.mouse_wheel_delay),
+            commands::mouse_speed_limit(self.mouse_speed_limit),
+        ]);
+        if let Some(value) = self.wireless_battery_saving_mode {
+            commands.push(commands::wireless_battery_saving_mode(value));
+        }
+        if let Some(value) = self.wireless_rf_power_level {
+            commands.push(commands::wireless_rf_power_level(value));
+        }
+        if let Some(value) = self.wireless_rf_channel_hop {
+            commands.push(commands::wireless_rf_channel_hop(value));
+        }
+
+        Ok(commands)
+    }
+}
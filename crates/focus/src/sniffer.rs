@@ -0,0 +1,130 @@
+use crate::builder::FocusBuilder;
+use crate::errors::FocusError;
+use crate::exchange::{Exchange, ExchangeTracker};
+use crate::transport::pty::{pty_builder, Pty};
+use crate::transport::Transport;
+use log::trace;
+use std::io::{ErrorKind, Read, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// How long each side is waited on before the other one gets a turn.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// A proxy that sits between a client such as Bazecor and the real keyboard, reporting every exchange.
+///
+/// The client connects to a pseudo terminal at `path`, everything it writes is passed to the device and back untouched.
+/// Responses are framed the same way `Focus` frames them, and paired with requests in the order they were sent.
+#[derive(Debug)]
+pub struct Sniffer {
+    client: Pty,
+    device: Box<dyn Transport>,
+    tracker: ExchangeTracker,
+}
+
+impl Sniffer {
+    /// Opens a pseudo terminal for the client, in front of the device transport.
+    ///
+    /// A transport for a real keyboard can be taken from `Focus::into_transport`.
+    pub fn new(device: impl Transport + 'static) -> Result<Self, FocusError> {
+        let mut client = Pty::open()?;
+        client.set_timeout(POLL_INTERVAL)?;
+        let mut device: Box<dyn Transport> = Box::new(device);
+        device.set_timeout(POLL_INTERVAL)?;
+
+        Ok(Self {
+            client,
+            device,
+            tracker: ExchangeTracker::default(),
+        })
+    }
+
+    /// Gets the path the client connects to, for example `/dev/pts/3`.
+    pub fn path(&self) -> &Path {
+        self.client.path()
+    }
+
+    /// Creates a builder connected to `path`.
+    pub fn builder(&self) -> FocusBuilder {
+        pty_builder(self.path())
+    }
+
+    /// Proxies until either side or `on_exchange` fails, calling `on_exchange` with every framed response or command that gets none.
+    pub fn run<F, E>(&mut self, mut on_exchange: F) -> Result<(), E>
+    where
+        F: FnMut(Exchange) -> Result<(), E>,
+        E: From<FocusError>,
+    {
+        let mut chunk = [0u8; 1024];
+        loop {
+            if let Some(size) = read_chunk(&mut self.client, &mut chunk)? {
+                self.device
+                    .write_all(&chunk[..size])
+                    .map_err(FocusError::SerialPortWriteError)?;
+                if let Some(exchange) = self.tracker.sent(&chunk[..size]) {
+                    trace!("Sniffed: {:?}", exchange);
+                    on_exchange(exchange)?;
+                }
+            }
+            if let Some(size) = read_chunk(&mut self.device, &mut chunk)? {
+                self.client
+                    .write_all(&chunk[..size])
+                    .map_err(FocusError::SerialPortWriteError)?;
+                for exchange in self.tracker.received(&chunk[..size]) {
+                    trace!("Sniffed: {:?}", exchange);
+                    on_exchange(exchange)?;
+                }
+            }
+        }
+    }
+}
+
+/// Reads whatever is available, `None` when the side was quiet for the poll interval.
+fn read_chunk(side: &mut impl Read, chunk: &mut [u8]) -> Result<Option<usize>, FocusError> {
+    match side.read(chunk) {
+        Ok(0) => Ok(None),
+        Ok(size) => Ok(Some(size)),
+        Err(e)
+            if matches!(
+                e.kind(),
+                ErrorKind::TimedOut | ErrorKind::WouldBlock | ErrorKind::Interrupted
+            ) =>
+        {
+            Ok(None)
+        }
+        Err(e) => Err(FocusError::SerialPortReadError(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_virtual::DEFY_WIRED_VIRTUAL;
+    use crate::prelude::*;
+    use std::sync::mpsc;
+    use std::thread;
+
+    #[test]
+    fn test_sniffer_pairs_exchanges() {
+        let mut sniffer = Sniffer::new(VirtualFocus::new(&DEFY_WIRED_VIRTUAL).unwrap()).unwrap();
+        let builder = sniffer.builder();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            sniffer.run(|exchange| {
+                let _ = sender.send(exchange);
+                Ok::<(), FocusError>(())
+            })
+        });
+
+        let mut focus = builder.build().unwrap();
+        focus.led_brightness_top_set(42).unwrap();
+        let version = focus.hardware_version_get().unwrap();
+
+        let set = receiver.recv().unwrap();
+        assert_eq!("led.brightness 42", set.request);
+        assert_eq!("", set.response);
+        let get = receiver.recv().unwrap();
+        assert_eq!("hardware.version", get.request);
+        assert_eq!(version, get.response);
+    }
+}
@@ -0,0 +1,407 @@
+use crate::errors::FocusError;
+use crate::key::Key;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ends a superkey, and the map after the last one.
+const END: u16 = 0;
+/// Stands for an action that is not set.
+const NO_ACTION: u16 = 1;
+/// Fills the unused memory after the map.
+const PADDING: u16 = 65535;
+/// The number of actions a superkey has.
+const ACTIONS: usize = 5;
+
+/// A superkey, running a different action depending on how the key is pressed.
+///
+/// Actions that are not set are `None`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_camel_case", serde(rename_all = "camelCase"))]
+pub struct Superkey {
+    pub tap: Option<Key>,
+    pub hold: Option<Key>,
+    pub tap_hold: Option<Key>,
+    pub double_tap: Option<Key>,
+    pub double_tap_hold: Option<Key>,
+}
+
+impl Superkey {
+    fn actions(&self) -> [Option<Key>; ACTIONS] {
+        [
+            self.tap,
+            self.hold,
+            self.tap_hold,
+            self.double_tap,
+            self.double_tap_hold,
+        ]
+    }
+
+    /// Fails for actions that are stored as the end marker or as no action, such as `Key::NoKey`.
+    fn check(&self) -> Result<(), FocusError> {
+        match self
+            .actions()
+            .into_iter()
+            .flatten()
+            .find(|action| matches!(action.encode(), END | NO_ACTION))
+        {
+            Some(action) => Err(FocusError::SuperkeyActionError {
+                action: action.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Encodes the superkey with its end marker, leaving out the trailing actions that are not set.
+    fn encode(&self) -> Vec<u16> {
+        let actions = self.actions();
+        // At least one action is kept, an empty superkey would otherwise read as the end of the map.
+        let used = actions
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(1, |last| last + 1);
+        actions[..used]
+            .iter()
+            .map(|action| action.map_or(NO_ACTION, Key::encode))
+            .chain([END])
+            .collect()
+    }
+}
+
+/// The superkeys map, as `superkeys_map_get` and `superkeys_map_set` exchange it.
+///
+/// Superkeys are referred to from the keymap by their index, as `Key::Superkey`.
+/// The map is validated against the memory the device has for it, which is the length of the map it reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Superkeys {
+    superkeys: Vec<Superkey>,
+    memory: usize,
+}
+
+impl Superkeys {
+    /// Creates an empty map, for a device with the memory for the map in values.
+    pub fn new(memory: usize) -> Self {
+        Self {
+            superkeys: Vec::new(),
+            memory,
+        }
+    }
+
+    /// Decodes the map reported by the device, its length is taken as the memory.
+    ///
+    /// An empty superkey keeps its index, so the ones after it stay where the keymap expects them.
+    /// Empty superkeys at the end of the map are left out.
+    pub fn decode(data: &[u16]) -> Result<Self, FocusError> {
+        let mut superkeys = Vec::new();
+        let mut actions = Vec::new();
+        for &value in data {
+            if value == END {
+                superkeys.push(superkey_from_actions(superkeys.len(), &actions)?);
+                actions.clear();
+            } else {
+                actions.push(value);
+            }
+        }
+        // Values after the last end marker are padding unless one of them is an action, as a map filling
+        // the whole memory has no room for its last end marker and may end with `Transparent` actions.
+        if actions.iter().any(|&value| value != PADDING) {
+            superkeys.push(superkey_from_actions(superkeys.len(), &actions)?);
+        }
+        while superkeys.last() == Some(&Superkey::default()) {
+            superkeys.pop();
+        }
+
+        Ok(Self {
+            superkeys,
+            memory: data.len(),
+        })
+    }
+
+    /// Encodes the map, padded to fill the memory.
+    ///
+    /// A map that fills the memory leaves out the end markers there is no room for.
+    pub fn encode(&self) -> Result<Vec<u16>, FocusError> {
+        for superkey in &self.superkeys {
+            superkey.check()?;
+        }
+        self.check_memory()?;
+        let mut data = self.data();
+        data.truncate(self.memory);
+        data.resize(self.memory, PADDING);
+        Ok(data)
+    }
+
+    /// Gets the memory for the map in values.
+    pub fn memory(&self) -> usize {
+        self.memory
+    }
+
+    /// Gets how much of the memory the map takes at least, leaving out the end markers it can do without.
+    pub fn used(&self) -> usize {
+        let data = self.data();
+        // The end of the map is optional, and so is the end of the last superkey, unless its actions would read as padding.
+        let mut used = data.len() - 1;
+        if let Some(actions) = data[..used].split(|&value| value == END).nth_back(1) {
+            if actions.iter().any(|&value| value != PADDING) {
+                used -= 1;
+            }
+        }
+        used
+    }
+
+    /// Gets the number of superkeys.
+    pub fn len(&self) -> usize {
+        self.superkeys.len()
+    }
+
+    /// Whether there are no superkeys.
+    pub fn is_empty(&self) -> bool {
+        self.superkeys.is_empty()
+    }
+
+    /// Gets the superkey at the index.
+    pub fn get(&self, index: usize) -> Option<&Superkey> {
+        self.superkeys.get(index)
+    }
+
+    /// Iterates over the superkeys in index order.
+    pub fn iter(&self) -> impl Iterator<Item = &Superkey> {
+        self.superkeys.iter()
+    }
+
+    /// Adds the superkey at the end, returning the key that runs it.
+    ///
+    /// Fails for actions that can't be stored, see `FocusError::SuperkeyActionError`.
+    pub fn add(&mut self, superkey: Superkey) -> Result<Key, FocusError> {
+        superkey.check()?;
+        self.superkeys.push(superkey);
+        if let Err(e) = self.check_memory() {
+            self.superkeys.pop();
+            return Err(e);
+        }
+        let key = Key::Superkey(self.superkeys.len() as u8 - 1);
+        if Key::decode(key.encode()) != key {
+            self.superkeys.pop();
+            return Err(FocusError::ValueAboveLimitError {
+                label: "superkey count",
+                max: self.superkeys.len(),
+                provided: self.superkeys.len() + 1,
+            });
+        }
+        Ok(key)
+    }
+
+    /// Replaces the superkey at the index.
+    ///
+    /// Fails for actions that can't be stored, see `FocusError::SuperkeyActionError`.
+    pub fn update(&mut self, index: usize, superkey: Superkey) -> Result<(), FocusError> {
+        self.check_index(index)?;
+        superkey.check()?;
+        let previous = std::mem::replace(&mut self.superkeys[index], superkey);
+        if let Err(e) = self.check_memory() {
+            self.superkeys[index] = previous;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Removes the superkey at the index, returning it.
+    ///
+    /// The superkeys after it move down by one, so keys referring to them have to be lowered as well.
+    pub fn remove(&mut self, index: usize) -> Result<Superkey, FocusError> {
+        self.check_index(index)?;
+        Ok(self.superkeys.remove(index))
+    }
+
+    fn check_index(&self, index: usize) -> Result<&Superkey, FocusError> {
+        self.superkeys
+            .get(index)
+            .ok_or(FocusError::ValueAboveLimitError {
+                label: "superkey",
+                max: self.superkeys.len().saturating_sub(1),
+                provided: index,
+            })
+    }
+
+    /// Encodes the superkeys followed by the end of the map.
+    fn data(&self) -> Vec<u16> {
+        self.superkeys
+            .iter()
+            .flat_map(Superkey::encode)
+            .chain([END])
+            .collect()
+    }
+
+    fn check_memory(&self) -> Result<(), FocusError> {
+        let used = self.used();
+        if used > self.memory {
+            return Err(FocusError::ValueAboveLimitError {
+                label: "superkeys memory",
+                max: self.memory,
+                provided: used,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn superkey_from_actions(index: usize, actions: &[u16]) -> Result<Superkey, FocusError> {
+    if actions.len() > ACTIONS {
+        return Err(FocusError::SuperkeyActionsError {
+            index,
+            actions: actions.len(),
+        });
+    }
+    let action = |position: usize| {
+        actions
+            .get(position)
+            .filter(|&&value| value != NO_ACTION)
+            .map(|&value| Key::decode(value))
+    };
+    Ok(Superkey {
+        tap: action(0),
+        hold: action(1),
+        tap_hold: action(2),
+        double_tap: action(3),
+        double_tap_hold: action(4),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_virtual::{DEVICES_VIRTUAL, RAISE_ISO_VIRTUAL};
+
+    fn parse(data: &str) -> Vec<u16> {
+        data.split_whitespace()
+            .map(|value| value.parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_superkeys_decode() {
+        let data = parse(RAISE_ISO_VIRTUAL.virtual_info.unwrap().superkeys_map.data);
+        let superkeys = Superkeys::decode(&data).unwrap();
+
+        assert_eq!(6, superkeys.len());
+        assert_eq!(&Superkey::default(), superkeys.get(1).unwrap());
+        assert_eq!(512, superkeys.memory());
+        assert_eq!(
+            &Superkey {
+                tap: Some("LCtrl+C".parse().unwrap()),
+                hold: Some("LCtrl+V".parse().unwrap()),
+                ..Superkey::default()
+            },
+            superkeys.get(0).unwrap()
+        );
+        assert_eq!(
+            &Superkey {
+                hold: Some("VolumeUp".parse().unwrap()),
+                tap_hold: Some("VolumeDown".parse().unwrap()),
+                ..Superkey::default()
+            },
+            superkeys.get(5).unwrap()
+        );
+
+        for hardware in DEVICES_VIRTUAL.iter() {
+            let data = parse(hardware.virtual_info.unwrap().superkeys_map.data);
+            let superkeys = Superkeys::decode(&data).unwrap();
+            let encoded = superkeys.encode().unwrap();
+            assert_eq!(data.len(), encoded.len());
+            assert_eq!(
+                superkeys,
+                Superkeys::decode(&encoded).unwrap(),
+                "{}",
+                hardware
+            );
+        }
+    }
+
+    #[test]
+    fn test_superkeys_edit() {
+        let mut superkeys = Superkeys::new(8);
+        let superkey = Superkey {
+            tap: Some(Key::Macro(1)),
+            double_tap: Some(Key::Transparent),
+            ..Superkey::default()
+        };
+
+        assert_eq!(Key::Superkey(0), superkeys.add(superkey).unwrap());
+        assert_eq!(
+            vec![53853, 1, 1, 65535, 0, 0, 65535, 65535],
+            superkeys.encode().unwrap()
+        );
+        assert!(superkeys.add(superkey).is_err());
+        superkeys.update(0, Superkey::default()).unwrap();
+        assert_eq!(Key::Superkey(1), superkeys.add(superkey).unwrap());
+        assert_eq!(Superkey::default(), superkeys.remove(0).unwrap());
+        assert!(superkeys.remove(1).is_err());
+        assert_eq!(
+            vec![53853, 1, 1, 65535, 0, 0],
+            superkeys.encode().unwrap()[..6]
+        );
+    }
+
+    #[test]
+    fn test_superkeys_reject_markers() {
+        let mut superkeys = Superkeys::new(8);
+        let superkey = Superkey {
+            tap: Some(Key::NoKey),
+            ..Superkey::default()
+        };
+
+        assert!(matches!(
+            superkeys.add(superkey),
+            Err(FocusError::SuperkeyActionError { .. })
+        ));
+        assert!(superkeys.is_empty());
+        superkeys.add(Superkey::default()).unwrap();
+        assert!(matches!(
+            superkeys.update(
+                0,
+                Superkey {
+                    hold: Some(Key::decode(NO_ACTION)),
+                    ..Superkey::default()
+                }
+            ),
+            Err(FocusError::SuperkeyActionError { .. })
+        ));
+        assert_eq!(&Superkey::default(), superkeys.get(0).unwrap());
+    }
+
+    #[test]
+    fn test_superkeys_trailing_transparent() {
+        let superkey = Superkey {
+            tap: Some(Key::Macro(1)),
+            hold: Some(Key::Transparent),
+            ..Superkey::default()
+        };
+
+        let mut superkeys = Superkeys::new(8);
+        superkeys.add(superkey).unwrap();
+        superkeys.add(superkey).unwrap();
+        assert_eq!(5, superkeys.used());
+        let encoded = superkeys.encode().unwrap();
+        assert_eq!(vec![53853, 65535, 0, 53853, 65535, 0, 0, 65535], encoded);
+        assert_eq!(superkeys, Superkeys::decode(&encoded).unwrap());
+
+        // The map fills the memory, leaving no room for the end markers.
+        let data = [53853, 65535];
+        let superkeys = Superkeys::decode(&data).unwrap();
+        assert_eq!(&superkey, superkeys.get(0).unwrap());
+        assert_eq!(data.to_vec(), superkeys.encode().unwrap());
+
+        // A superkey made of `Transparent` actions only needs its end marker to not read as padding.
+        let mut superkeys = Superkeys::new(2);
+        let transparent = Superkey {
+            tap: Some(Key::Transparent),
+            ..Superkey::default()
+        };
+        superkeys.add(transparent).unwrap();
+        assert_eq!(vec![65535, 0], superkeys.encode().unwrap());
+        assert_eq!(superkeys, Superkeys::decode(&[65535, 0]).unwrap());
+        assert!(Superkeys::new(1).add(transparent).is_err());
+    }
+}
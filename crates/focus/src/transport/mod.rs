@@ -0,0 +1,148 @@
+use crate::errors::FocusError;
+use std::fmt::Debug;
+use std::io::{Read, Write};
+use std::time::Duration;
+
+#[cfg(target_os = "linux")]
+pub mod pty;
+#[cfg(all(feature = "fixtures", not(target_arch = "wasm32")))]
+pub mod record;
+#[cfg(all(feature = "fixtures", not(target_arch = "wasm32")))]
+pub mod replay;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod serial;
+pub mod simulator;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod socket;
+
+/// A byte stream that the Focus protocol can be spoken over.
+///
+/// Reading, writing and flushing come from [`Read`] and [`Write`].
+/// Serial ports, sockets, pseudo terminals and mocks can all be used as the backend of a `Focus`.
+pub trait Transport: Read + Write + Send + Debug {
+    /// Gets how long a single read may block before timing out.
+    fn timeout(&self) -> Duration;
+
+    /// Sets how long a single read may block before timing out.
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError>;
+
+    /// Gets the error a response stands for, when something between the client and the device can answer in its place.
+    ///
+    /// Default: every response comes from the device.
+    fn response_error(&self, _command: &str, _response: &[u8]) -> Option<FocusError> {
+        None
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn timeout(&self) -> Duration {
+        (**self).timeout()
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        (**self).set_timeout(timeout)
+    }
+
+    fn response_error(&self, command: &str, response: &[u8]) -> Option<FocusError> {
+        (**self).response_error(command, response)
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::collections::VecDeque;
+    use std::io::ErrorKind;
+
+    #[derive(Debug, Default)]
+    struct MockTransport {
+        written: Vec<u8>,
+        responses: VecDeque<u8>,
+    }
+
+    impl Read for MockTransport {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.responses.is_empty() {
+                return Err(ErrorKind::TimedOut.into());
+            }
+            self.responses.read(buf)
+        }
+    }
+
+    impl Write for MockTransport {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.written.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Transport for MockTransport {
+        fn timeout(&self) -> Duration {
+            Duration::from_secs(1)
+        }
+
+        fn set_timeout(&mut self, _timeout: Duration) -> Result<(), FocusError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_times_out() {
+        let mut focus = Focus::new_via_transport(MockTransport::default());
+        focus.set_command_timeout(Some(Duration::from_millis(20)));
+
+        let actual = focus.version();
+
+        assert!(matches!(
+            actual,
+            Err(FocusError::Timeout { command, .. }) if command == "version"
+        ));
+    }
+
+    #[test]
+    fn test_read_cancelled() {
+        let mut focus = Focus::new_via_transport(MockTransport::default());
+        let cancel = focus.cancel_handle();
+
+        std::thread::spawn(move || cancel.cancel()).join().unwrap();
+        let actual = focus.version();
+
+        assert!(matches!(actual, Err(FocusError::Cancelled { .. })));
+    }
+
+    #[test]
+    fn test_focus_via_transport() {
+        let transport = MockTransport {
+            written: Vec::new(),
+            responses: b"v1.0.9beta\r\n.\r\n".iter().copied().collect(),
+        };
+        let mut focus = Focus::new_via_transport(transport);
+
+        let actual = focus.version().unwrap();
+
+        assert_eq!("v1.0.9beta", actual);
+    }
+
+    #[test]
+    fn test_batch_pipelined() {
+        let transport = MockTransport {
+            written: Vec::new(),
+            responses: b"v1.0.9beta\r\n.\r\n0 1\r\n.\r\n\r\n.\r\n"
+                .iter()
+                .copied()
+                .collect(),
+        };
+        let mut focus = Focus::new_via_transport(transport);
+
+        let actual = focus
+            .batch(&["version", "layer.state", "led.mode 1"])
+            .unwrap();
+
+        assert_eq!(vec!["v1.0.9beta", "0 1", ""], actual);
+    }
+}
@@ -0,0 +1,250 @@
+use crate::builder::FocusBuilder;
+use crate::errors::FocusError;
+use crate::hardware::Hardware;
+use crate::transport::simulator::VirtualFocus;
+use crate::transport::Transport;
+use log::{debug, trace};
+use std::ffi::{CStr, OsStr};
+use std::fs::{File, OpenOptions};
+use std::io::{ErrorKind, Read, Write};
+use std::os::fd::{AsRawFd, FromRawFd};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// How often a served pseudo terminal checks whether it should stop.
+const SERVE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A pseudo terminal pair, this end is the master and clients open the slave at `path`.
+///
+/// The slave is kept open as well, so clients can disconnect and reconnect without the master hanging up.
+/// The slave is put in raw mode, so bytes pass through untouched like on a real serial port.
+#[derive(Debug)]
+pub struct Pty {
+    master: File,
+    _slave: File,
+    path: PathBuf,
+    timeout: Duration,
+}
+
+impl Pty {
+    /// Creates a new pseudo terminal pair.
+    pub fn open() -> Result<Self, FocusError> {
+        // SAFETY: the descriptor is checked before being owned by the file, and the name buffer is nul terminated by `ptsname_r`.
+        let (master, path) = unsafe {
+            let fd = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY);
+            if fd < 0 {
+                return Err(last_error());
+            }
+            let master = File::from_raw_fd(fd);
+            if libc::grantpt(fd) != 0 || libc::unlockpt(fd) != 0 {
+                return Err(last_error());
+            }
+            let mut name = [0 as libc::c_char; 128];
+            if libc::ptsname_r(fd, name.as_mut_ptr(), name.len()) != 0 {
+                return Err(last_error());
+            }
+            let path = PathBuf::from(OsStr::from_bytes(CStr::from_ptr(name.as_ptr()).to_bytes()));
+            (master, path)
+        };
+
+        let slave = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(libc::O_NOCTTY)
+            .open(&path)
+            .map_err(FocusError::PtyError)?;
+        // SAFETY: the termios struct is filled by `tcgetattr` before it is read.
+        unsafe {
+            let mut termios = std::mem::zeroed::<libc::termios>();
+            if libc::tcgetattr(slave.as_raw_fd(), &mut termios) != 0 {
+                return Err(last_error());
+            }
+            libc::cfmakeraw(&mut termios);
+            if libc::tcsetattr(slave.as_raw_fd(), libc::TCSANOW, &termios) != 0 {
+                return Err(last_error());
+            }
+        }
+        debug!("Opened pseudo terminal: {}", path.display());
+
+        Ok(Self {
+            master,
+            _slave: slave,
+            path,
+            timeout: Duration::from_secs(5),
+        })
+    }
+
+    /// Gets the path of the slave end, for example `/dev/pts/3`.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Answers every command written to the slave end with the simulated keyboard, until `stop` is set.
+    pub fn serve(
+        &mut self,
+        device: &mut VirtualFocus,
+        stop: &AtomicBool,
+    ) -> Result<(), FocusError> {
+        let mut chunk = [0u8; 1024];
+        while !stop.load(Ordering::Relaxed) {
+            match self.read(&mut chunk) {
+                Ok(size) => {
+                    trace!("Pty RX: {:02X?}", &chunk[..size]);
+                    device
+                        .write_all(&chunk[..size])
+                        .map_err(FocusError::SerialPortWriteError)?;
+                }
+                Err(e) if matches!(e.kind(), ErrorKind::TimedOut | ErrorKind::Interrupted) => {
+                    continue
+                }
+                Err(e) => return Err(FocusError::SerialPortReadError(e)),
+            }
+            while let Ok(size) = device.read(&mut chunk) {
+                if size == 0 {
+                    break;
+                }
+                self.write_all(&chunk[..size])
+                    .map_err(FocusError::SerialPortWriteError)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Read for Pty {
+    /// Waits up to the timeout for data, as reading the master directly would block forever.
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut poll = libc::pollfd {
+            fd: self.master.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = self.timeout.as_millis().min(libc::c_int::MAX as u128) as libc::c_int;
+        // SAFETY: a single valid pollfd is passed.
+        match unsafe { libc::poll(&mut poll, 1, timeout) } {
+            0 => Err(ErrorKind::TimedOut.into()),
+            n if n < 0 => Err(std::io::Error::last_os_error()),
+            _ => self.master.read(buf),
+        }
+    }
+}
+
+impl Write for Pty {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.master.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.master.flush()
+    }
+}
+
+impl Transport for Pty {
+    fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        self.timeout = timeout;
+        Ok(())
+    }
+}
+
+/// A simulated keyboard served on a pseudo terminal from a background thread.
+///
+/// Anything that can open a serial port, such as Bazecor or `VirtualPty::builder`, can connect to `path`.
+/// The thread stops when this is dropped.
+#[derive(Debug)]
+pub struct VirtualPty {
+    path: PathBuf,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<Result<(), FocusError>>>,
+}
+
+impl VirtualPty {
+    /// Opens a pseudo terminal and starts serving the virtual definition of the hardware on it.
+    pub fn spawn(hardware: &Hardware) -> Result<Self, FocusError> {
+        let mut device = VirtualFocus::new(hardware)?;
+        let mut pty = Pty::open()?;
+        pty.set_timeout(SERVE_POLL_INTERVAL)?;
+        let path = pty.path().to_path_buf();
+        let stop = Arc::new(AtomicBool::new(false));
+        let handle = thread::spawn({
+            let stop = stop.clone();
+            move || pty.serve(&mut device, &stop)
+        });
+
+        Ok(Self {
+            path,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    /// Gets the path clients connect to, for example `/dev/pts/3`.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Creates a builder connected to `path`.
+    pub fn builder(&self) -> FocusBuilder {
+        pty_builder(&self.path)
+    }
+
+    /// Blocks until serving fails, which does not happen on its own while the pseudo terminal is open.
+    pub fn wait(mut self) -> Result<(), FocusError> {
+        match self.handle.take().map(JoinHandle::join) {
+            Some(Ok(result)) => result,
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Drop for VirtualPty {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Creates a builder connected to the pseudo terminal at the path.
+pub(crate) fn pty_builder(path: &Path) -> FocusBuilder {
+    FocusBuilder::new().port(&path.to_string_lossy())
+}
+
+fn last_error() -> FocusError {
+    FocusError::PtyError(std::io::Error::last_os_error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_virtual::DEFY_WIRED_VIRTUAL;
+    use crate::platform::posix::Focus;
+
+    #[test]
+    fn test_virtual_pty() {
+        let pty = VirtualPty::spawn(&DEFY_WIRED_VIRTUAL).unwrap();
+
+        let mut focus = pty.builder().build().unwrap();
+
+        assert_eq!("Dygma Defy", focus.hardware_version_get().unwrap());
+        assert_eq!(800, focus.keymap_custom_get().unwrap().len());
+    }
+
+    #[test]
+    fn test_virtual_pty_via_port() {
+        let pty = VirtualPty::spawn(&DEFY_WIRED_VIRTUAL).unwrap();
+
+        let mut focus = Focus::new_via_port(&pty.path().to_string_lossy()).unwrap();
+
+        assert_eq!("Dygma Defy", focus.hardware_version_get().unwrap());
+    }
+}
@@ -0,0 +1,72 @@
+use crate::errors::FocusError;
+use crate::exchange::{ExchangeTracker, FixtureWriter};
+use crate::transport::Transport;
+use log::trace;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Passes everything through to another transport, saving each exchange to a fixture file.
+///
+/// The fixture can be served back with `ReplayTransport`, and has the same format as the sniffing proxy writes.
+#[derive(Debug)]
+pub struct RecordingTransport<T: Transport> {
+    inner: T,
+    tracker: ExchangeTracker,
+    writer: FixtureWriter,
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    /// Records the exchanges over the transport to the fixture file, replacing an existing one.
+    pub fn new(inner: T, path: impl AsRef<Path>) -> Result<Self, FocusError> {
+        Ok(Self {
+            inner,
+            tracker: ExchangeTracker::default(),
+            writer: FixtureWriter::create(path)?,
+        })
+    }
+}
+
+impl<T: Transport> Read for RecordingTransport<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let size = self.inner.read(buf)?;
+        for exchange in self.tracker.received(&buf[..size]) {
+            trace!("Recorded: {:?}", exchange);
+            self.writer
+                .write(&exchange)
+                .map_err(std::io::Error::other)?;
+        }
+        Ok(size)
+    }
+}
+
+impl<T: Transport> Write for RecordingTransport<T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let size = self.inner.write(buf)?;
+        if let Some(exchange) = self.tracker.sent(&buf[..size]) {
+            trace!("Recorded: {:?}", exchange);
+            self.writer
+                .write(&exchange)
+                .map_err(std::io::Error::other)?;
+        }
+        Ok(size)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<T: Transport> Transport for RecordingTransport<T> {
+    fn timeout(&self) -> Duration {
+        self.inner.timeout()
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        self.inner.set_timeout(timeout)
+    }
+
+    fn response_error(&self, command: &str, response: &[u8]) -> Option<FocusError> {
+        self.inner.response_error(command, response)
+    }
+}
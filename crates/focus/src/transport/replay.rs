@@ -0,0 +1,173 @@
+use crate::errors::FocusError;
+use crate::exchange::{read_fixture, Exchange};
+use crate::framer::{whitespace_command, EOF_MARKER};
+use crate::transport::Transport;
+use log::trace;
+use std::collections::VecDeque;
+use std::io::{ErrorKind, Read, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Serves the responses of a recorded session, failing the write of any request that differs from the recording.
+///
+/// Requests must arrive in the same order they were recorded in.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    exchanges: VecDeque<Exchange>,
+    input: Vec<u8>,
+    output: VecDeque<u8>,
+    timeout: Duration,
+}
+
+impl ReplayTransport {
+    /// Replays the exchanges in order.
+    pub fn new(exchanges: Vec<Exchange>) -> Self {
+        Self {
+            exchanges: exchanges.into(),
+            input: Vec::new(),
+            output: VecDeque::new(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// Replays the exchanges from a fixture file, as written by `RecordingTransport` or the sniffing proxy.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, FocusError> {
+        Ok(Self::new(read_fixture(path)?))
+    }
+
+    /// Gets the recorded exchanges that have not been requested yet.
+    pub fn remaining(&self) -> usize {
+        self.exchanges.len()
+    }
+
+    /// Takes the next recorded exchange, if it was for `request`.
+    fn next_exchange(&mut self, request: &str) -> std::io::Result<Exchange> {
+        match self.exchanges.pop_front() {
+            Some(exchange) if exchange.request == request => {
+                trace!("Replayed: {:?}", exchange);
+                Ok(exchange)
+            }
+            Some(exchange) => Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "request `{}` does not match the recorded `{}`",
+                    request, exchange.request
+                ),
+            )),
+            None => Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("request `{}` is past the end of the recording", request),
+            )),
+        }
+    }
+}
+
+impl Read for ReplayTransport {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.output.is_empty() {
+            return Err(ErrorKind::TimedOut.into());
+        }
+        self.output.read(buf)
+    }
+}
+
+impl Write for ReplayTransport {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.input.extend_from_slice(buf);
+        while let Some(pos) = self.input.iter().position(|&b| b == b'\n') {
+            let line = self.input.drain(..=pos).collect::<Vec<u8>>();
+            let request = String::from_utf8_lossy(&line);
+            let request = request.trim();
+            if request.is_empty() {
+                continue;
+            }
+            let exchange = self.next_exchange(request)?;
+            self.output.extend(exchange.response.as_bytes());
+            self.output.extend(EOF_MARKER);
+        }
+        if let Some(name) = whitespace_command(&self.input) {
+            let request = String::from_utf8_lossy(name).into_owned();
+            self.input.clear();
+            self.next_exchange(&request)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        self.timeout = timeout;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_virtual::DEFY_WIRELESS_VIRTUAL;
+    use crate::prelude::*;
+    use crate::transport::record::RecordingTransport;
+
+    #[test]
+    fn test_record_then_replay() {
+        let path = std::env::temp_dir().join(format!("dygma_focus_{}.jsonl", std::process::id()));
+        let device = VirtualFocus::new(&DEFY_WIRELESS_VIRTUAL).unwrap();
+        let mut focus = Focus::new_via_transport(RecordingTransport::new(device, &path).unwrap());
+        let recorded = focus.settings_get().unwrap();
+        let mut settings = recorded.clone();
+        settings.mouse_speed = 100;
+        focus.settings_set(&settings).unwrap();
+        drop(focus);
+
+        let mut focus = Focus::new_via_transport(ReplayTransport::open(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(recorded, focus.settings_get().unwrap());
+        settings.mouse_speed = 101;
+        assert!(matches!(
+            focus.settings_set(&settings),
+            Err(FocusError::SerialPortWriteError(_))
+        ));
+    }
+
+    #[test]
+    fn test_record_whitespace_command() {
+        let path = std::env::temp_dir().join(format!(
+            "dygma_focus_whitespace_{}.jsonl",
+            std::process::id()
+        ));
+        let device = VirtualFocus::new(&DEFY_WIRELESS_VIRTUAL).unwrap();
+        let mut focus = Focus::new_via_transport(RecordingTransport::new(device, &path).unwrap());
+        focus.upgrade_keyscanner_send_write().unwrap();
+        let version = focus.hardware_version_get().unwrap();
+        drop(focus);
+
+        let exchanges = read_fixture(&path).unwrap();
+        let requests = exchanges
+            .iter()
+            .map(|exchange| exchange.request.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            vec!["upgrade.keyscanner.sendWrite", "hardware.version"],
+            requests
+        );
+        assert_eq!(version, exchanges[1].response);
+        assert!(exchanges
+            .iter()
+            .all(|exchange| exchange.response_timestamp >= exchange.timestamp));
+
+        let mut focus = Focus::new_via_transport(ReplayTransport::open(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        focus.upgrade_keyscanner_send_write().unwrap();
+        assert_eq!(version, focus.hardware_version_get().unwrap());
+    }
+}
@@ -0,0 +1,26 @@
+use crate::errors::FocusError;
+use crate::transport::Transport;
+use serialport::SerialPort;
+use std::time::Duration;
+
+#[cfg(unix)]
+impl Transport for serialport::TTYPort {
+    fn timeout(&self) -> Duration {
+        SerialPort::timeout(self)
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        SerialPort::set_timeout(self, timeout).map_err(FocusError::SerialPortConfigurationError)
+    }
+}
+
+#[cfg(windows)]
+impl Transport for serialport::COMPort {
+    fn timeout(&self) -> Duration {
+        SerialPort::timeout(self)
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        SerialPort::set_timeout(self, timeout).map_err(FocusError::SerialPortConfigurationError)
+    }
+}
@@ -0,0 +1,357 @@
+use crate::color::RGB;
+use crate::errors::FocusError;
+use crate::framer::whitespace_command;
+use crate::hardware::Hardware;
+use crate::transport::Transport;
+use log::trace;
+use std::collections::{HashMap, VecDeque};
+use std::io::{Read, Write};
+use std::time::Duration;
+
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+
+/// The number of layers reported by `layer.state`.
+const LAYER_STATE_COUNT: usize = 32;
+
+/// A simulated keyboard that answers Focus commands from the `Virtual` definition of a device.
+///
+/// Writing to erasable nodes updates the in-memory state, writes to read only nodes are ignored.
+/// Responses are framed the same way as the firmware, so it can be used anywhere a serial port can.
+#[derive(Debug)]
+pub struct VirtualFocus {
+    hardware: Hardware,
+    nodes: HashMap<&'static str, Node>,
+    leds: HashMap<u8, RGB>,
+    led_fill: RGB,
+    layers: Vec<bool>,
+    input: Vec<u8>,
+    output: VecDeque<u8>,
+    timeout: Duration,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    data: String,
+    erasable: bool,
+}
+
+impl VirtualFocus {
+    /// Creates a new simulated keyboard from hardware that has a virtual definition.
+    pub fn new(hardware: &Hardware) -> Result<Self, FocusError> {
+        let virtual_info = hardware
+            .virtual_info
+            .ok_or(FocusError::NoVirtualInfoError {
+                name: hardware.info.display_name,
+            })?;
+        let nodes = virtual_info
+            .nodes()
+            .into_iter()
+            .filter_map(|(command, node)| {
+                node.map(|node| {
+                    (
+                        command,
+                        Node {
+                            data: node.data.to_string(),
+                            erasable: node.erasable,
+                        },
+                    )
+                })
+            })
+            .collect::<HashMap<_, _>>();
+        let mut layers = nodes
+            .get("layer.state")
+            .map(|node| {
+                node.data
+                    .split_whitespace()
+                    .map(|part| part == "1")
+                    .collect::<Vec<bool>>()
+            })
+            .unwrap_or_default();
+        layers.resize(LAYER_STATE_COUNT, false);
+
+        Ok(Self {
+            hardware: *hardware,
+            nodes,
+            leds: HashMap::new(),
+            led_fill: RGB { r: 0, g: 0, b: 0 },
+            layers,
+            input: Vec::new(),
+            output: VecDeque::new(),
+            timeout: Duration::from_secs(5),
+        })
+    }
+
+    /// Gets the hardware being simulated.
+    pub fn hardware(&self) -> &Hardware {
+        &self.hardware
+    }
+
+    /// Gets the current data of a node by its Focus command, if the device supports it.
+    pub fn node(&self, command: &str) -> Option<&str> {
+        self.nodes.get(command).map(|node| node.data.as_str())
+    }
+
+    /// Runs a single command line and returns the unframed response.
+    fn execute(&mut self, line: &str) -> String {
+        let (command, args) = match line.split_once(char::is_whitespace) {
+            Some((command, args)) => (command, args.trim()),
+            None => (line, ""),
+        };
+        trace!("Virtual RX: {} {}", command, args);
+
+        match command {
+            "led.at" => self.led_at(args),
+            "led.setAll" => {
+                if let Ok(color) = args.parse::<RGB>() {
+                    self.leds.clear();
+                    self.led_fill = color;
+                }
+                String::new()
+            }
+            "layer.activate" => {
+                if let Some(layer) = self.layer_arg(args) {
+                    self.layers[layer] = true;
+                }
+                String::new()
+            }
+            "layer.deactivate" => {
+                match self.layer_arg(args) {
+                    Some(layer) => self.layers[layer] = false,
+                    None => {
+                        if let Some(top) = self.layers.iter().rposition(|&active| active) {
+                            if top > 0 {
+                                self.layers[top] = false;
+                            }
+                        }
+                    }
+                }
+                String::new()
+            }
+            "layer.isActive" => match self.layer_arg(args) {
+                Some(layer) => (self.layers[layer] as u8).to_string(),
+                None => String::new(),
+            },
+            "layer.moveTo" => {
+                if let Some(layer) = self.layer_arg(args) {
+                    self.layers.iter_mut().for_each(|active| *active = false);
+                    self.layers[layer] = true;
+                }
+                String::new()
+            }
+            "layer.state" => self
+                .layers
+                .iter()
+                .map(|&active| (active as u8).to_string())
+                .collect::<Vec<String>>()
+                .join(" "),
+            "macros.trigger" => String::new(),
+            _ => match self.nodes.get_mut(command) {
+                Some(node) if args.is_empty() => node.data.clone(),
+                Some(node) if node.erasable => {
+                    node.data = args.to_string();
+                    String::new()
+                }
+                Some(_) => {
+                    trace!("Virtual ignored write to read only node: {}", command);
+                    String::new()
+                }
+                None => String::new(),
+            },
+        }
+    }
+
+    fn led_at(&mut self, args: &str) -> String {
+        let (led, color) = match args.split_once(char::is_whitespace) {
+            Some((led, color)) => (led, Some(color)),
+            None => (args, None),
+        };
+        let Ok(led) = led.parse::<u8>() else {
+            return String::new();
+        };
+        match color.map(str::parse::<RGB>) {
+            Some(Ok(color)) => {
+                self.leds.insert(led, color);
+                String::new()
+            }
+            Some(Err(_)) => String::new(),
+            None => {
+                let color = self.leds.get(&led).unwrap_or(&self.led_fill);
+                format!("{} {} {}", color.r, color.g, color.b)
+            }
+        }
+    }
+
+    fn layer_arg(&self, args: &str) -> Option<usize> {
+        args.parse::<usize>()
+            .ok()
+            .filter(|&layer| layer < self.layers.len())
+    }
+}
+
+impl Read for VirtualFocus {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.output.is_empty() {
+            return Err(std::io::ErrorKind::TimedOut.into());
+        }
+        self.output.read(buf)
+    }
+}
+
+impl Write for VirtualFocus {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.input.extend_from_slice(buf);
+        while let Some(pos) = self.input.iter().position(|&b| b == b'\n') {
+            let line = self.input.drain(..=pos).collect::<Vec<u8>>();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let response = self.execute(line);
+            self.output.extend(response.as_bytes());
+            self.output.extend(b"\r\n.\r\n");
+        }
+        if let Some(name) = whitespace_command(&self.input) {
+            trace!("Virtual ignored: {}", String::from_utf8_lossy(name));
+            self.input.clear();
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl tokio::io::AsyncRead for VirtualFocus {
+    /// Stays pending while there is nothing to read, the caller's timeout ends the wait.
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        if this.output.is_empty() {
+            return Poll::Pending;
+        }
+        let size = this.output.read(buf.initialize_unfilled())?;
+        buf.advance(size);
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "async")]
+impl tokio::io::AsyncWrite for VirtualFocus {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Poll::Ready(self.get_mut().write(buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Transport for VirtualFocus {
+    fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        self.timeout = timeout;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_physical::DEFY_WIRED;
+    use crate::hardware::types::hardware_virtual::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_virtual_settings_get() {
+        for hardware in DEVICES_VIRTUAL.iter() {
+            let mut focus = Focus::new_virtual(hardware).unwrap();
+
+            let settings = focus.settings_get().unwrap();
+
+            assert_eq!(800, settings.keymap_custom.len());
+        }
+    }
+
+    #[test]
+    fn test_virtual_settings_set() {
+        let mut focus = Focus::new_virtual(&DEFY_WIRELESS_VIRTUAL).unwrap();
+        let mut settings = focus.settings_get().unwrap();
+        settings.keymap_custom[0] = 41;
+        settings.mouse_speed = 100;
+
+        focus.settings_set(&settings).unwrap();
+
+        assert_eq!(settings, focus.settings_get().unwrap());
+
+        settings.mouse_speed = 128;
+        assert!(focus.settings_set(&settings).is_err());
+    }
+
+    #[test]
+    fn test_virtual_framing() {
+        let mut device = VirtualFocus::new(&DEFY_WIRED_VIRTUAL).unwrap();
+        device.write_all(b"led.mode\n").unwrap();
+
+        let mut response = String::new();
+        device.output.read_to_string(&mut response).unwrap();
+
+        assert_eq!("0\r\n.\r\n", response);
+    }
+
+    #[test]
+    fn test_virtual_write_erasable() {
+        let mut focus = Focus::new_virtual(&DEFY_WIRED_VIRTUAL).unwrap();
+
+        focus.led_brightness_top_set(42).unwrap();
+
+        assert_eq!(42, focus.led_brightness_top_get().unwrap());
+    }
+
+    #[test]
+    fn test_virtual_write_read_only() {
+        let mut focus = Focus::new_virtual(&DEFY_WIRED_VIRTUAL).unwrap();
+
+        focus.hardware_version_set("Dygma Raise").unwrap();
+
+        assert_eq!("Dygma Defy", focus.hardware_version_get().unwrap());
+    }
+
+    #[test]
+    fn test_virtual_layers() {
+        let mut focus = Focus::new_virtual(&RAISE_ANSI_VIRTUAL).unwrap();
+
+        focus.layer_move_to(3).unwrap();
+
+        assert!(focus.layer_is_active(3).unwrap());
+        assert!(!focus.layer_is_active(0).unwrap());
+        assert_eq!(
+            Some(3),
+            focus.layer_state().unwrap().iter().position(|&x| x)
+        );
+    }
+
+    #[test]
+    fn test_virtual_requires_definition() {
+        assert!(VirtualFocus::new(&DEFY_WIRED).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::errors::FocusError;
+use crate::transport::Transport;
+use crate::DEFAULT_READ_TIMEOUT;
+use std::net::TcpStream;
+use std::time::Duration;
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+
+// Sockets start without a read timeout, so the default one is reported until it is set.
+// `Focus::new_via_transport` applies it, which keeps a silent peer from blocking a read forever.
+impl Transport for TcpStream {
+    fn timeout(&self) -> Duration {
+        self.read_timeout()
+            .ok()
+            .flatten()
+            .unwrap_or(DEFAULT_READ_TIMEOUT)
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        self.set_read_timeout(Some(timeout))
+            .map_err(|e| FocusError::SerialPortConfigurationError(e.into()))
+    }
+}
+
+#[cfg(unix)]
+impl Transport for UnixStream {
+    fn timeout(&self) -> Duration {
+        self.read_timeout()
+            .ok()
+            .flatten()
+            .unwrap_or(DEFAULT_READ_TIMEOUT)
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> Result<(), FocusError> {
+        self.set_read_timeout(Some(timeout))
+            .map_err(|e| FocusError::SerialPortConfigurationError(e.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::io::{ErrorKind, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn test_peer_closed_mid_response() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let peer = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 64];
+            let _ = stream.read(&mut request).unwrap();
+            stream.write_all(b"v1.0").unwrap();
+        });
+
+        let mut focus = Focus::new_via_transport(TcpStream::connect(address).unwrap());
+        assert_eq!(DEFAULT_READ_TIMEOUT, focus.read_timeout());
+        let actual = focus.version();
+        peer.join().unwrap();
+
+        assert!(matches!(
+            actual,
+            Err(FocusError::SerialPortReadError(e)) if e.kind() == ErrorKind::UnexpectedEof
+        ));
+    }
+}
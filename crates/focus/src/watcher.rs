@@ -0,0 +1,247 @@
+use crate::hardware::Device;
+use log::{debug, trace};
+use std::collections::HashSet;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+#[cfg(unix)]
+use crate::platform::posix::Focus;
+#[cfg(windows)]
+use crate::platform::windows::Focus;
+
+/// How many scans a removed device is held back for, so it can still be matched when it comes back in or out of its bootloader.
+const MODE_CHANGE_SCANS: usize = 3;
+
+/// A change in the set of connected devices.
+#[derive(Debug, Clone)]
+pub enum DeviceEvent {
+    /// A device was connected, devices already connected when watching starts are reported as arrived.
+    Arrived(Box<Device>),
+    /// A device was disconnected, reported once it has not come back in the other mode for a few scans.
+    Removed(Box<Device>),
+    /// A device re-enumerated into or out of its bootloader.
+    ModeChanged { from: Box<Device>, to: Box<Device> },
+}
+
+/// Watches for devices being connected and disconnected.
+///
+/// Ports are scanned on a background thread, which stops when the watcher is dropped.
+#[derive(Debug)]
+pub struct DeviceWatcher {
+    receiver: Receiver<DeviceEvent>,
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl DeviceWatcher {
+    /// Starts watching, scanning the serial ports at the provided interval.
+    pub fn new(interval: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let (stop, stopped) = mpsc::channel::<()>();
+        let handle = thread::spawn(move || {
+            let mut tracker = DeviceTracker::default();
+            loop {
+                match Focus::find_all_devices() {
+                    Ok(current) => {
+                        for event in tracker.scan(current) {
+                            trace!("Device event: {:?}", event);
+                            if sender.send(event).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Err(e) => debug!("Device scan failed: {}", e),
+                }
+                if stopped.recv_timeout(interval) != Err(RecvTimeoutError::Timeout) {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            receiver,
+            stop: Some(stop),
+            handle: Some(handle),
+        }
+    }
+
+    /// Blocks until the next event.
+    pub fn recv(&self) -> Option<DeviceEvent> {
+        self.receiver.recv().ok()
+    }
+
+    /// Blocks until the next event or until the timeout passes.
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<DeviceEvent> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+
+    /// Gets the next event if there is one waiting.
+    pub fn try_recv(&self) -> Option<DeviceEvent> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Iterates over the events, blocking while waiting for each one.
+    pub fn iter(&self) -> impl Iterator<Item = DeviceEvent> + '_ {
+        self.receiver.iter()
+    }
+}
+
+impl Drop for DeviceWatcher {
+    fn drop(&mut self) {
+        self.stop.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Turns successive scans into events, remembering removed devices for a few scans.
+///
+/// A keyboard resetting into its bootloader can take more than one scan to come back, so it is only reported
+/// as removed once it has not reappeared in the other mode for `MODE_CHANGE_SCANS` scans.
+#[derive(Debug, Default)]
+struct DeviceTracker {
+    devices: Vec<Device>,
+    /// Removed devices that are not reported yet, with the number of scans they have been missing for.
+    removed: Vec<(Device, usize)>,
+}
+
+impl DeviceTracker {
+    /// Compares the scan with the previous one and returns the events that turn the first into the second.
+    fn scan(&mut self, mut current: Vec<Device>) -> Vec<DeviceEvent> {
+        // Products sharing a USB id, such as the Raise 2 ANSI and ISO, are all detected on the port of one keyboard.
+        let mut ports = HashSet::new();
+        current.retain(|device| ports.insert(device.serial_port.clone()));
+
+        for device in &self.devices {
+            if !current.iter().any(|other| same_port(device, other)) {
+                self.removed.push((device.clone(), 0));
+            }
+        }
+        let mut events = Vec::new();
+        for device in &current {
+            if self.devices.iter().any(|other| same_port(device, other)) {
+                continue;
+            }
+            match self
+                .removed
+                .iter()
+                .position(|(other, _)| is_mode_change(other, device))
+            {
+                Some(index) => events.push(DeviceEvent::ModeChanged {
+                    from: Box::new(self.removed.remove(index).0),
+                    to: Box::new(device.clone()),
+                }),
+                None => {
+                    // The same device coming back in the same mode is reported as removed before it arrives again.
+                    if let Some(index) = self
+                        .removed
+                        .iter()
+                        .position(|(other, _)| same_port(other, device))
+                    {
+                        events.push(DeviceEvent::Removed(Box::new(self.removed.remove(index).0)));
+                    }
+                    events.push(DeviceEvent::Arrived(Box::new(device.clone())));
+                }
+            }
+        }
+        self.removed.retain_mut(|(device, scans)| {
+            *scans += 1;
+            if *scans < MODE_CHANGE_SCANS {
+                return true;
+            }
+            events.push(DeviceEvent::Removed(Box::new(device.clone())));
+            false
+        });
+        self.devices = current;
+        events
+    }
+}
+
+/// Whether both are the same device on the same port.
+fn same_port(a: &Device, b: &Device) -> bool {
+    a.serial_port == b.serial_port
+        && a.hardware.usb.vendor_id == b.hardware.usb.vendor_id
+        && a.hardware.usb.product_id == b.hardware.usb.product_id
+}
+
+/// Whether the arrival is the removed device coming back in or out of its bootloader.
+fn is_mode_change(removed: &Device, arrived: &Device) -> bool {
+    let serial_number_matches = match (&removed.serial_number, &arrived.serial_number) {
+        (Some(a), Some(b)) => a == b,
+        _ => true,
+    };
+    removed.hardware.info.product == arrived.hardware.info.product
+        && removed.hardware.bootloader != arrived.hardware.bootloader
+        && serial_number_matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::types::hardware_physical::*;
+    use crate::hardware::Hardware;
+
+    fn device(hardware: Hardware, port: &str) -> Device {
+        Device {
+            hardware,
+            serial_port: port.to_string(),
+            serial_number: None,
+        }
+    }
+
+    #[test]
+    fn test_tracker_arrived_and_removed() {
+        let mut tracker = DeviceTracker::default();
+        tracker.scan(vec![device(DEFY_WIRED, "/dev/ttyACM0")]);
+
+        let events = tracker.scan(vec![device(RAISE_ANSI, "/dev/ttyACM1")]);
+        assert!(matches!(&events[..], [
+            DeviceEvent::Arrived(arrived),
+        ] if arrived.serial_port == "/dev/ttyACM1"));
+        for _ in 2..MODE_CHANGE_SCANS {
+            assert!(tracker
+                .scan(vec![device(RAISE_ANSI, "/dev/ttyACM1")])
+                .is_empty());
+        }
+        let events = tracker.scan(vec![device(RAISE_ANSI, "/dev/ttyACM1")]);
+        assert!(matches!(&events[..], [
+            DeviceEvent::Removed(removed),
+        ] if removed.serial_port == "/dev/ttyACM0"));
+    }
+
+    #[test]
+    fn test_tracker_mode_changed() {
+        let mut tracker = DeviceTracker::default();
+        tracker.scan(vec![device(DEFY_WIRED, "/dev/ttyACM0")]);
+
+        assert!(tracker.scan(Vec::new()).is_empty());
+        let events = tracker.scan(vec![device(DEFY_WIRED_BOOTLOADER, "/dev/ttyACM1")]);
+
+        assert!(matches!(&events[..], [
+            DeviceEvent::ModeChanged { from, to },
+        ] if !from.hardware.bootloader && to.hardware.bootloader));
+    }
+
+    #[test]
+    fn test_tracker_unchanged() {
+        let mut tracker = DeviceTracker::default();
+        let current = vec![device(DEFY_WIRED, "/dev/ttyACM0")];
+
+        assert_eq!(1, tracker.scan(current.clone()).len());
+        assert!(tracker.scan(current).is_empty());
+    }
+
+    #[test]
+    fn test_tracker_shared_product_id() {
+        let mut tracker = DeviceTracker::default();
+
+        let events = tracker.scan(vec![
+            device(RAISE_2_ANSI, "/dev/ttyACM0"),
+            device(RAISE_2_ISO, "/dev/ttyACM0"),
+        ]);
+
+        assert!(matches!(&events[..], [DeviceEvent::Arrived(_)]));
+    }
+}